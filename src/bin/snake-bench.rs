@@ -0,0 +1,84 @@
+// Plays seeded headless games with each bot and reports how they did:
+//
+//     cargo run --release --bin snake-bench -- --games 100 --threads 4 --bots astar,hamiltonian
+use snake_game::{
+    bot::{bench_bot, format_table, BenchOptions, BOT_NAMES},
+    config::Difficulty,
+    gameplay::GameMode,
+    utils::Result,
+};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: snake-bench [--games N] [--bots a,b] [--seed N] [--threads N] \
+[--max-ticks N] [--preset easy|normal|hard|insane] [--endless] [--json]";
+
+struct Args {
+    options: BenchOptions,
+    json: bool,
+}
+
+fn parse_args() -> std::result::Result<Args, String> {
+    let mut args = Args { options: BenchOptions::default(), json: false };
+    let mut iter = std::env::args().skip(1);
+
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| iter.next().ok_or_else(|| format!("{} expects a value", name));
+        let options = &mut args.options;
+        match arg.as_str() {
+            "--games" => options.games = number(&arg, &value(&arg)?)?,
+            "--seed" => options.seed = number(&arg, &value(&arg)?)?,
+            "--threads" => options.threads = number(&arg, &value(&arg)?)?,
+            "--max-ticks" => options.max_ticks = number(&arg, &value(&arg)?)?,
+            "--bots" => options.bots = value(&arg)?.split(',').map(|name| name.trim().to_string()).collect(),
+            "--preset" => {
+                let name = value(&arg)?;
+                options.difficulty = Difficulty::from_name(&name)
+                    .ok_or_else(|| format!("unknown preset '{}'", name))?;
+            }
+            "--endless" => options.mode = GameMode::Endless,
+            "--json" => args.json = true,
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            other => return Err(format!("unknown argument '{}'\n{}", other, USAGE)),
+        }
+    }
+
+    if let Some(name) = args.options.bots.iter().find(|name| !BOT_NAMES.contains(&name.as_str())) {
+        return Err(format!("unknown bot '{}', expected some of: {}", name, BOT_NAMES.join(", ")));
+    }
+    Ok(args)
+}
+
+fn number<T: std::str::FromStr>(flag: &str, value: &str) -> std::result::Result<T, String> {
+    value.parse().map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn run(args: &Args) -> Result<()> {
+    let reports = args.options.bots.iter()
+        .map(|bot| bench_bot(bot, &args.options))
+        .collect::<Result<Vec<_>>>()?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    } else {
+        print!("{}", format_table(&reports));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// src/bot/astar.rs
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+};
+use crate::entities::{Direction, Point};
+use super::{Bot, GameView};
+
+// Plans the shortest route to the food, but only takes it if the snake can
+// still reach its own tail after eating. Otherwise it follows its tail, which
+// keeps a way out open until the food becomes safe to fetch.
+#[derive(Debug, Default)]
+pub struct AStarBot {
+    path: Vec<Point>,  // Squares still to visit, next step first
+    safe: bool,        // Whether the path leads to the food rather than the tail
+}
+
+impl AStarBot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The route the bot settled on at its last move
+    pub fn path(&self) -> &[Point] {
+        &self.path
+    }
+
+    // False when eating would have trapped the snake, so it's biding its time
+    pub fn found_safe_path(&self) -> bool {
+        self.safe
+    }
+}
+
+impl Bot for AStarBot {
+    fn name(&self) -> &'static str {
+        "astar"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        let body = view.body();
+        let head = view.head();
+
+        let food_path = find_path(view, body, view.food())
+            .filter(|path| tail_reachable_after(view, body, path));
+        self.safe = food_path.is_some();
+        self.path = match food_path {
+            Some(path) => path,
+            None => body.front()
+                .and_then(|tail| find_path(view, body, *tail))
+                .unwrap_or_default(),
+        };
+
+        let planned = self.path.first().and_then(|next| {
+            view.neighbours(&head).find(|(_, point)| point == next).map(|(direction, _)| direction)
+        });
+        planned.unwrap_or_else(|| roomiest_move(view, body))
+    }
+}
+
+// Shortest path from the head (the back of `body`) to `goal`, not counting the head.
+// A body square is only blocked until the tail has moved off it: the segment
+// `i` places from the tail is gone after `i + 1` moves.
+pub(crate) fn find_path(view: &GameView, body: &VecDeque<Point>, goal: Point) -> Option<Vec<Point>> {
+    let start = *body.back()?;
+    let segments: HashMap<Point, usize> = body.iter().enumerate().map(|(i, point)| (*point, i)).collect();
+
+    let mut open = BinaryHeap::new();
+    let mut best: HashMap<Point, u32> = HashMap::from([(start, 0)]);
+    let mut came_from: HashMap<Point, Point> = HashMap::new();
+    open.push(Reverse((view.distance(&start, &goal), 0, start.x, start.y)));
+
+    while let Some(Reverse((_, steps, x, y))) = open.pop() {
+        let point = Point::new(x, y);
+        if point == goal {
+            let mut path = vec![point];
+            while let Some(previous) = came_from.get(path.last()?).filter(|p| **p != start) {
+                path.push(*previous);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if best.get(&point).is_some_and(|&known| known < steps) {
+            continue;
+        }
+
+        let next_steps = steps + 1;
+        for (_, next) in view.neighbours(&point) {
+            let still_body = segments.get(&next).is_some_and(|&i| (next_steps as usize) < i + 1);
+            if still_body || view.is_blocked_by_others(&next) {
+                continue;
+            }
+            if best.get(&next).is_some_and(|&known| known <= next_steps) {
+                continue;
+            }
+            best.insert(next, next_steps);
+            came_from.insert(next, point);
+            open.push(Reverse((next_steps + view.distance(&next, &goal), next_steps, next.x, next.y)));
+        }
+    }
+    None
+}
+
+// Where the body ends up after following `path` and eating at its end
+pub(crate) fn body_after(body: &VecDeque<Point>, path: &[Point]) -> VecDeque<Point> {
+    let mut body = body.clone();
+    for (i, point) in path.iter().enumerate() {
+        body.push_back(*point);
+        if i + 1 < path.len() {
+            body.pop_front();
+        }
+    }
+    body
+}
+
+fn tail_reachable_after(view: &GameView, body: &VecDeque<Point>, path: &[Point]) -> bool {
+    let body = body_after(body, path);
+    body.front().is_some_and(|tail| find_path(view, &body, *tail).is_some())
+}
+
+// Squares reachable from `start` without crossing anything, including the body as it is now
+pub(crate) fn reachable_area(view: &GameView, body: &VecDeque<Point>, start: Point) -> usize {
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(point) = queue.pop_front() {
+        for (_, next) in view.neighbours(&point) {
+            if !body.contains(&next) && !view.is_blocked_by_others(&next) && seen.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    seen.len()
+}
+
+// Last resort: the safe move with the most room behind it
+pub(crate) fn roomiest_move(view: &GameView, body: &VecDeque<Point>) -> Direction {
+    let tail = body.front().copied();
+    view.neighbours(&view.head())
+        .filter(|(direction, point)| {
+            *direction != view.direction().opposite() &&
+            !view.is_blocked_by_others(point) &&
+            (!body.contains(point) || Some(*point) == tail)
+        })
+        .max_by_key(|(_, point)| reachable_area(view, body, *point))
+        .map_or(view.direction(), |(direction, _)| direction)
+}
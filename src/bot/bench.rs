@@ -0,0 +1,187 @@
+// src/bot/bench.rs
+use std::{
+    collections::BTreeMap,
+    thread,
+    time::{Duration, Instant},
+};
+use serde::Serialize;
+use crate::{
+    config::{Config, Difficulty},
+    core::GameState,
+    gameplay::{GameEndReason, GameEvent, GameMode, GameState as GameStateEnum},
+    utils::{Result, GameError},
+};
+use super::{bot_by_name, Bot, GameView, BOT_NAMES};
+
+// What to run: `games` games per bot, seeded `seed`, `seed + 1`, ...
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    pub bots: Vec<String>,
+    pub games: u32,
+    pub seed: u64,
+    pub threads: usize,
+    pub max_ticks: u64,  // Games still going after this many ticks are cut off
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            bots: BOT_NAMES.iter().map(|name| name.to_string()).collect(),
+            games: 20,
+            seed: 0,
+            threads: 1,
+            max_ticks: 20_000,
+            difficulty: Difficulty::Normal,
+            mode: GameMode::Classic,
+        }
+    }
+}
+
+// How a single game went
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameOutcome {
+    pub seed: u64,
+    pub score: u32,
+    pub levels_cleared: u32,
+    pub ticks: u64,
+    pub ending: String,  // Death cause, or how the game ended otherwise
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchReport {
+    pub bot: String,
+    pub games: u32,
+    pub mean_score: f64,
+    pub median_score: f64,
+    pub mean_levels_cleared: f64,
+    pub endings: BTreeMap<String, u32>,
+    pub ticks: u64,
+    pub ticks_per_second: f64,
+}
+
+// Plays one headless game to the end, or until `max_ticks`
+pub fn play_game(bot: &mut dyn Bot, mut config: Config, seed: u64, max_ticks: u64) -> Result<GameOutcome> {
+    config.seed = Some(seed);
+    let mut game = GameState::with_config(config);
+    let mut levels_cleared = 0;
+    let mut ticks = 0;
+
+    while ticks < max_ticks {
+        match game.game_state() {
+            GameStateEnum::Playing => {}
+            GameStateEnum::LevelTransition => game.start_next_level(),
+            GameStateEnum::Paused | GameStateEnum::GameOver(_) => break,
+        }
+
+        let direction = bot.next_direction(&GameView::new(&game));
+        game.change_direction(direction);
+        game.update()?;
+        ticks += 1;
+
+        // Nobody else reads the events, so they'd pile up otherwise
+        levels_cleared += game.drain_events().iter()
+            .filter(|event| matches!(event, GameEvent::LevelComplete { .. }))
+            .count() as u32;
+    }
+
+    let ending = match game.game_state() {
+        GameStateEnum::GameOver(GameEndReason::Collision) => game.death_cause().map_or("collision", |cause| cause.name()),
+        GameStateEnum::GameOver(GameEndReason::OutOfMoves) => "out of moves",
+        GameStateEnum::GameOver(GameEndReason::Victory) => "victory",
+        GameStateEnum::GameOver(GameEndReason::BoardFull) => "board full",
+        GameStateEnum::GameOver(GameEndReason::Winner(_) | GameEndReason::Draw) => "match over",
+        _ => "tick limit",
+    };
+
+    Ok(GameOutcome { seed, score: game.score(), levels_cleared, ticks, ending: ending.to_string() })
+}
+
+// Runs every game for one bot, spread over `options.threads` threads
+pub fn bench_bot(name: &str, options: &BenchOptions) -> Result<BenchReport> {
+    if bot_by_name(name).is_none() {
+        return Err(GameError::InvalidInput(format!("unknown bot '{}', expected one of {:?}", name, BOT_NAMES)));
+    }
+
+    let threads = options.threads.clamp(1, options.games.max(1) as usize);
+    let started = Instant::now();
+    let outcomes = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|worker| {
+            scope.spawn(move || {
+                let mut bot = bot_by_name(name).expect("bot name checked above");
+                (0..options.games)
+                    .filter(|game| *game as usize % threads == worker)
+                    .map(|game| {
+                        let config = Config::from_difficulty(options.difficulty);
+                        let config = Config { mode: options.mode, ..config };
+                        play_game(bot.as_mut(), config, options.seed + u64::from(game), options.max_ticks)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+        }).collect();
+
+        workers.into_iter()
+            .map(|worker| worker.join().map_err(|_| GameError::GameState(format!("bot '{}' panicked", name)))?)
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut outcomes: Vec<GameOutcome> = outcomes.into_iter().flatten().collect();
+    outcomes.sort_by_key(|outcome| outcome.seed);
+    Ok(summarize(name, &outcomes, started.elapsed()))
+}
+
+pub fn summarize(bot: &str, outcomes: &[GameOutcome], elapsed: Duration) -> BenchReport {
+    let games = outcomes.len() as u32;
+    let mean = |total: f64| if games == 0 { 0.0 } else { total / f64::from(games) };
+
+    let mut scores: Vec<u32> = outcomes.iter().map(|outcome| outcome.score).collect();
+    scores.sort_unstable();
+    let median_score = match scores.len() {
+        0 => 0.0,
+        n if n & 1 == 1 => f64::from(scores[n / 2]),
+        n => (f64::from(scores[n / 2 - 1]) + f64::from(scores[n / 2])) / 2.0,
+    };
+
+    let mut endings = BTreeMap::new();
+    for outcome in outcomes {
+        *endings.entry(outcome.ending.clone()).or_insert(0) += 1;
+    }
+
+    let ticks = outcomes.iter().map(|outcome| outcome.ticks).sum();
+    BenchReport {
+        bot: bot.to_string(),
+        games,
+        mean_score: mean(scores.iter().map(|&score| f64::from(score)).sum()),
+        median_score,
+        mean_levels_cleared: mean(outcomes.iter().map(|outcome| f64::from(outcome.levels_cleared)).sum()),
+        endings,
+        ticks,
+        ticks_per_second: ticks as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+    }
+}
+
+// One row per bot, with the endings spelled out at the end
+pub fn format_table(reports: &[BenchReport]) -> String {
+    let mut table = format!(
+        "{:<12} {:>6} {:>8} {:>8} {:>8} {:>12}  {}\n",
+        "bot", "games", "mean", "median", "levels", "ticks/s", "endings"
+    );
+    for report in reports {
+        let endings = report.endings.iter()
+            .map(|(ending, count)| format!("{} {}", ending, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        table.push_str(&format!(
+            "{:<12} {:>6} {:>8.1} {:>8.1} {:>8.2} {:>12.0}  {}\n",
+            report.bot,
+            report.games,
+            report.mean_score,
+            report.median_score,
+            report.mean_levels_cleared,
+            report.ticks_per_second,
+            endings
+        ));
+    }
+    table
+}
@@ -0,0 +1,209 @@
+// src/bot/external.rs
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+use serde::Serialize;
+use crate::{
+    config::BORDER_THICKNESS,
+    entities::{Direction, Point},
+    gameplay::WallMode,
+    utils::{Result, GameError},
+};
+use super::{Bot, GameView};
+
+pub const DEFAULT_MOVE_TIMEOUT: Duration = Duration::from_millis(100);
+
+// What the program is sent each tick, as one line of JSON. Coordinates count
+// from the top-left playable square, so walls sit just outside 0..width and 0..height.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BotState {
+    pub tick: u64,
+    pub width: u16,
+    pub height: u16,
+    pub wrap: bool,
+    pub direction: &'static str,
+    pub snake: Vec<[u16; 2]>,        // Head first
+    pub others: Vec<Vec<[u16; 2]>>,  // Other snakes still on the board, head first
+    pub food: [u16; 2],
+    pub obstacles: Vec<[u16; 2]>,
+    pub pickups: Vec<[u16; 2]>,
+    pub exit_gate: Option<[u16; 2]>,
+    pub score: u32,
+    pub level: u32,
+}
+
+impl BotState {
+    pub fn new(view: &GameView, tick: u64) -> Self {
+        let game = view.game();
+        let (width, height) = view.dimensions();
+        let snake = |body: &std::collections::VecDeque<Point>| body.iter().rev().map(coordinates).collect();
+
+        let mut obstacles: Vec<[u16; 2]> = view.obstacles().iter()
+            .flat_map(|obstacle| &obstacle.blocks)
+            .map(coordinates)
+            .collect();
+        // Blocks are kept in a set, so give them a stable order
+        obstacles.sort_unstable();
+
+        Self {
+            tick,
+            width: width.saturating_sub(2 * BORDER_THICKNESS),
+            height: height.saturating_sub(2 * BORDER_THICKNESS),
+            wrap: view.wall_mode() == WallMode::Wrap,
+            direction: view.direction().name(),
+            snake: snake(view.body()),
+            others: game.players().iter().enumerate()
+                .filter(|(index, player)| *index != view.player() && player.is_alive())
+                .map(|(_, player)| snake(player.snake().body()))
+                .collect(),
+            food: coordinates(&view.food()),
+            obstacles,
+            pickups: game.pickups().iter().map(coordinates).collect(),
+            exit_gate: game.exit_gate().map(coordinates),
+            score: game.players()[view.player()].score(),
+            level: game.current_level(),
+        }
+    }
+}
+
+fn coordinates(point: &Point) -> [u16; 2] {
+    [point.x.saturating_sub(BORDER_THICKNESS), point.y.saturating_sub(BORDER_THICKNESS)]
+}
+
+// A bot in another process, in any language. It gets a `BotState` line on
+// stdin every tick and answers with a line saying up, down, left or right.
+// A reply that's late or unreadable leaves the snake going straight for that
+// tick; once the program exits the snake goes straight for the rest of the game.
+pub struct ExternalBot {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    replies: Receiver<String>,
+    owed: usize,  // Replies still to come for states that timed out
+    timeout: Duration,
+    tick: u64,
+    problem: Option<String>,
+}
+
+impl ExternalBot {
+    // Runs `command` through the shell, so arguments and quoting work as usual
+    pub fn spawn(command: &str, timeout: Duration) -> Result<Self> {
+        let mut child = shell(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            // Anything it prints there would scribble over the board
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| GameError::InvalidInput(format!("can't start bot '{}': {}", command, e)))?;
+
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take()
+            .ok_or_else(|| GameError::GameState("bot has no stdout".to_string()))?;
+
+        // Reading on a thread of its own is what lets a move time out
+        let (sender, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(std::io::Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { child, stdin, replies, owed: 0, timeout, tick: 0, problem: None })
+    }
+
+    fn ask(&mut self, view: &GameView) -> std::result::Result<Direction, String> {
+        let stdin = self.stdin.as_mut().ok_or_else(|| self.problem.clone().unwrap_or_default())?;
+
+        let state = serde_json::to_string(&BotState::new(view, self.tick)).map_err(|e| e.to_string())?;
+        if writeln!(stdin, "{}", state).and_then(|_| stdin.flush()).is_err() {
+            return Err(self.exited());
+        }
+
+        // The game waits on the answer, so a tick is the most the bot can have
+        let timeout = self.timeout.min(Duration::from_millis(view.game().get_tick_rate()));
+        let deadline = Instant::now() + timeout;
+
+        // Every state gets one reply, so the first ones to arrive may belong to
+        // ticks that already timed out; those are thrown away
+        let reply = loop {
+            match self.replies.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(_) if self.owed > 0 => self.owed -= 1,
+                other => break other,
+            }
+        };
+
+        match reply {
+            Ok(reply) => Direction::from_name(reply.trim())
+                .ok_or_else(|| format!("bot replied '{}', expected up, down, left or right", reply.trim())),
+            Err(RecvTimeoutError::Timeout) => {
+                self.owed += 1;
+                Err(format!("bot took longer than {}ms to move", timeout.as_millis()))
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(self.exited()),
+        }
+    }
+
+    // Stops talking to a program that has gone away
+    fn exited(&mut self) -> String {
+        self.stdin = None;
+        match self.child.try_wait() {
+            Ok(Some(status)) => format!("bot program exited ({})", status),
+            _ => "bot program closed its output".to_string(),
+        }
+    }
+}
+
+impl Bot for ExternalBot {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        self.tick += 1;
+        match self.ask(view) {
+            Ok(direction) => {
+                self.problem = None;
+                direction
+            }
+            Err(problem) => {
+                if self.problem.as_ref() != Some(&problem) {
+                    log::warn!("{}", problem);
+                }
+                self.problem = Some(problem);
+                view.direction()
+            }
+        }
+    }
+
+    fn warning(&self) -> Option<String> {
+        self.problem.clone()
+    }
+}
+
+impl Drop for ExternalBot {
+    fn drop(&mut self) {
+        // The bot sees its input end, but isn't waited on to notice
+        self.stdin = None;
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
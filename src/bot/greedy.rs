@@ -0,0 +1,31 @@
+// src/bot/greedy.rs
+use crate::entities::Direction;
+use super::{Bot, GameView};
+
+// Heads straight for the food, only avoiding squares that are blocked right now.
+// Cheap and easily trapped, but a useful baseline for the other bots.
+#[derive(Debug, Default)]
+pub struct GreedyBot;
+
+impl GreedyBot {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Bot for GreedyBot {
+    fn name(&self) -> &'static str {
+        "greedy"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        let head = view.head();
+        let food = view.food();
+
+        // Take the safe move that gets closest to the food; with none left, carry on
+        view.neighbours(&head)
+            .filter(|(direction, point)| *direction != view.direction().opposite() && !view.is_blocked(point))
+            .min_by_key(|(_, point)| view.distance(point, &food))
+            .map_or(view.direction(), |(direction, _)| direction)
+    }
+}
@@ -0,0 +1,304 @@
+// src/bot/hamiltonian.rs
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::{
+    entities::{Direction, Point},
+    config::BORDER_THICKNESS,
+    utils::{Result, GameError},
+};
+use super::{Bot, GameView, AStarBot, astar::roomiest_move};
+
+// Extra room kept free ahead of the head when cutting across the cycle, so a
+// few meals in a row can't close the gap to the tail
+const SHORTCUT_MARGIN: usize = 4;
+
+// Follows a cycle through every free square, which can never trap the snake,
+// and cuts across it towards the food while the snake is short enough to do so
+// safely. Plays like the A* bot on boards where no cycle can be built.
+#[derive(Debug, Default)]
+pub struct HamiltonianBot {
+    board: Option<((u16, u16), Vec<Point>)>,  // Board the cycle was built for
+    order: HashMap<Point, usize>,             // Position of each square along the cycle
+    cycle: Vec<Point>,
+    error: Option<String>,
+    fallback: AStarBot,
+}
+
+impl HamiltonianBot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Obstacles change between levels, so the cycle is rebuilt whenever the board does
+    fn prepare(&mut self, view: &GameView) {
+        let mut obstacles: Vec<Point> = view.obstacles().iter().flat_map(|o| o.blocks.iter().copied()).collect();
+        obstacles.sort_by_key(|point| (point.y, point.x));
+        let board = (view.dimensions(), obstacles);
+        if self.board.as_ref() == Some(&board) {
+            return;
+        }
+
+        match hamiltonian_cycle(view) {
+            Ok(cycle) => {
+                self.order = cycle.iter().enumerate().map(|(i, point)| (*point, i)).collect();
+                self.cycle = cycle;
+                self.error = None;
+            }
+            Err(e) => {
+                log::warn!("Hamiltonian bot falling back to A*: {}", e);
+                self.order.clear();
+                self.cycle.clear();
+                self.error = Some(match e {
+                    GameError::GameState(reason) => reason,
+                    other => other.to_string(),
+                });
+            }
+        }
+        self.board = Some(board);
+    }
+
+    // Steps forward along the cycle from `a` to `b`
+    fn distance(&self, a: &Point, b: &Point) -> usize {
+        let n = self.cycle.len();
+        (self.order[b] + n - self.order[a]) % n
+    }
+
+    // Whether the body runs along the cycle from tail to head, which the
+    // shortcut rule relies on. A fresh snake may lie across it for a while.
+    fn aligned(&self, body: &VecDeque<Point>) -> bool {
+        let (Some(tail), Some(head)) = (body.front(), body.back()) else {
+            return false;
+        };
+        let span = self.distance(tail, head);
+        span + 1 == body.len() && body.iter().all(|point| self.distance(tail, point) <= span)
+    }
+}
+
+impl Bot for HamiltonianBot {
+    fn name(&self) -> &'static str {
+        "hamiltonian"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        self.prepare(view);
+        if self.error.is_some() {
+            return self.fallback.next_direction(view);
+        }
+
+        let body = view.body();
+        let head = view.head();
+        let tail = body.front().copied().unwrap_or(head);
+        let food = view.food();
+
+        // Squares the head can step onto this tick; the tail moves out of the way
+        let moves: Vec<(Direction, Point)> = view.neighbours(&head)
+            .filter(|(direction, point)| {
+                *direction != view.direction().opposite() &&
+                self.order.contains_key(point) &&
+                !view.is_blocked_by_others(point) &&
+                (!body.contains(point) || *point == tail)
+            })
+            .collect();
+
+        let successor = self.cycle[(self.order[&head] + 1) % self.cycle.len()];
+        let mut best = moves.iter().find(|(_, point)| *point == successor).copied();
+
+        // Only cut across while the snake covers less than half the cycle, never past the food,
+        // and always leaving room to grow before reaching the tail
+        if best.is_some() && body.len() * 2 < self.cycle.len() && self.aligned(body) {
+            let to_food = self.distance(&head, &food);
+            let to_tail = self.distance(&head, &tail);
+            let shortcut = moves.iter()
+                .filter(|(_, point)| {
+                    let ahead = self.distance(&head, point);
+                    ahead <= to_food && ahead + SHORTCUT_MARGIN < to_tail
+                })
+                .max_by_key(|(_, point)| self.distance(&head, point));
+            if let Some(shortcut) = shortcut {
+                best = Some(*shortcut);
+            }
+        }
+
+        best.map_or_else(|| roomiest_move(view, body), |(direction, _)| direction)
+    }
+
+    fn warning(&self) -> Option<String> {
+        self.error.clone()
+    }
+}
+
+// A cycle through every free square of the playing area, in the order the snake
+// visits them. Without obstacles any board with an even number of squares has
+// one; around obstacles it's built from 2x2 blocks, so those have to line up.
+// An odd side leaves a one-square strip along the wall, which the cycle dips
+// into from the blocks beside it.
+pub fn hamiltonian_cycle(view: &GameView) -> Result<Vec<Point>> {
+    let (width, height) = view.dimensions();
+    let columns = width.saturating_sub(2 * BORDER_THICKNESS);
+    let rows = height.saturating_sub(2 * BORDER_THICKNESS);
+    let at = |x: u16, y: u16| Point::new(BORDER_THICKNESS + x, BORDER_THICKNESS + y);
+
+    if columns < 2 || rows < 2 {
+        return Err(no_cycle(format!("a {}x{} playing area is too small", columns, rows)));
+    }
+
+    let blocked: HashSet<(u16, u16)> = (0..columns)
+        .flat_map(|x| (0..rows).map(move |y| (x, y)))
+        .filter(|&(x, y)| view.is_wall(&at(x, y)))
+        .collect();
+
+    // Squares alternate colours like a chessboard and a cycle alternates with them
+    let free = usize::from(columns) * usize::from(rows) - blocked.len();
+    if free & 1 == 1 {
+        return Err(no_cycle(format!(
+            "a {}x{} playing area with {} free squares has an odd number of squares, so no cycle can visit each once",
+            columns, rows, free
+        )));
+    }
+
+    if blocked.is_empty() {
+        // Rows snake back and forth, and the first column leads back to the start
+        return if rows & 1 == 0 {
+            Ok(serpentine(columns, rows).into_iter().map(|(x, y)| at(x, y)).collect())
+        } else {
+            Ok(serpentine(rows, columns).into_iter().map(|(y, x)| at(x, y)).collect())
+        };
+    }
+
+    if columns & 1 == 1 && rows & 1 == 1 {
+        return Err(unsupported(format!(
+            "a cycle around obstacles needs an even number of columns or rows, this playing area is {}x{}",
+            columns, rows
+        )));
+    }
+
+    // Each 2x2 block has to be either completely free or completely blocked
+    let (block_columns, block_rows) = (columns / 2, rows / 2);
+    let mut free_blocks = HashSet::new();
+    for bx in 0..block_columns {
+        for by in 0..block_rows {
+            let cells = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(dx, dy)| (bx * 2 + dx, by * 2 + dy));
+            match cells.iter().filter(|cell| blocked.contains(cell)).count() {
+                0 => { free_blocks.insert((bx, by)); }
+                4 => {}
+                _ => {
+                    let (x, y) = cells[0];
+                    let corner = at(x, y);
+                    return Err(unsupported(format!(
+                        "the obstacle near ({}, {}) doesn't line up with the 2x2 grid the cycle is built on",
+                        corner.x, corner.y
+                    )));
+                }
+            }
+        }
+    }
+
+    // The strip squares beside each free block along an odd side, which the cycle
+    // visits as a detour from that block. Squares next to a blocked one have to be blocked.
+    let strip = if rows & 1 == 1 {
+        (0..block_columns).map(|bx| ((bx, block_rows - 1), [(bx * 2, rows - 1), (bx * 2 + 1, rows - 1)])).collect::<Vec<_>>()
+    } else if columns & 1 == 1 {
+        (0..block_rows).map(|by| ((block_columns - 1, by), [(columns - 1, by * 2), (columns - 1, by * 2 + 1)])).collect()
+    } else {
+        Vec::new()
+    };
+    let mut detours = HashSet::new();
+    for (block, cells) in strip {
+        let open = cells.iter().filter(|cell| !blocked.contains(cell)).count();
+        match (open, free_blocks.contains(&block)) {
+            (0, _) => {}
+            (2, true) => { detours.insert(block); }
+            _ => {
+                let corner = at(cells[0].0, cells[0].1);
+                return Err(unsupported(format!(
+                    "the squares near ({}, {}) along the wall can't be joined to the 2x2 grid the cycle is built on",
+                    corner.x, corner.y
+                )));
+            }
+        }
+    }
+
+    // Spanning tree over the free blocks; the cycle runs around its outline
+    let Some(&root) = free_blocks.iter().min() else {
+        return Err(no_cycle("obstacles cover the whole board".to_string()));
+    };
+    let mut links = HashSet::new();  // (block, neighbour to its right or below)
+    let mut seen = HashSet::from([root]);
+    let mut queue = VecDeque::from([root]);
+    while let Some((bx, by)) = queue.pop_front() {
+        let neighbours = [
+            (bx.wrapping_sub(1), by),
+            (bx + 1, by),
+            (bx, by.wrapping_sub(1)),
+            (bx, by + 1),
+        ];
+        for next in neighbours {
+            if free_blocks.contains(&next) && seen.insert(next) {
+                links.insert(((bx, by).min(next), (bx, by).max(next)));
+                queue.push_back(next);
+            }
+        }
+    }
+    if seen.len() < free_blocks.len() {
+        return Err(no_cycle("obstacles cut the board into separate parts".to_string()));
+    }
+
+    // Inside a block the cycle turns anticlockwise; a link to the next block
+    // reroutes one corner of each block through the other
+    let step = |x: u16, y: u16| -> (u16, u16) {
+        let block = ((x / 2).min(block_columns - 1), (y / 2).min(block_rows - 1));
+        let in_strip = (rows & 1 == 1 && y == rows - 1) || (columns & 1 == 1 && x == columns - 1);
+        let direction = match (x & 1, y & 1) {
+            // Along the bottom strip: down from the block, across, and back up
+            (0, 1) if rows & 1 == 1 && y == rows - 2 && detours.contains(&block) => Direction::Down,
+            (0, 0) if in_strip && rows & 1 == 1 => Direction::Right,
+            (1, 0) if in_strip && rows & 1 == 1 => Direction::Up,
+            // Along the right strip: out of the block, up, and back in
+            (1, 1) if columns & 1 == 1 && x == columns - 2 && detours.contains(&block) => Direction::Right,
+            (0, 1) if in_strip => Direction::Up,
+            (0, 0) if in_strip => Direction::Left,
+            (1, 1) if links.contains(&(block, (block.0 + 1, block.1))) => Direction::Right,
+            (0, 0) if block.0 > 0 && links.contains(&((block.0 - 1, block.1), block)) => Direction::Left,
+            (0, 1) if links.contains(&(block, (block.0, block.1 + 1))) => Direction::Down,
+            (1, 0) if block.1 > 0 && links.contains(&((block.0, block.1 - 1), block)) => Direction::Up,
+            (0, 0) => Direction::Down,
+            (0, _) => Direction::Right,
+            (_, 1) => Direction::Up,
+            _ => Direction::Left,
+        };
+        let next = Point::new(x, y).translate(&direction);
+        (next.x, next.y)
+    };
+
+    let start = (root.0 * 2, root.1 * 2);
+    let mut cycle = vec![at(start.0, start.1)];
+    let mut cell = step(start.0, start.1);
+    while cell != start {
+        cycle.push(at(cell.0, cell.1));
+        cell = step(cell.0, cell.1);
+    }
+    Ok(cycle)
+}
+
+// Visits a `columns` x `rows` grid (with an even number of rows) and ends next to where it started
+fn serpentine(columns: u16, rows: u16) -> Vec<(u16, u16)> {
+    let mut cells = Vec::new();
+    for y in 0..rows {
+        if y & 1 == 0 {
+            cells.extend((1..columns).map(|x| (x, y)));
+        } else {
+            cells.extend((1..columns).rev().map(|x| (x, y)));
+        }
+    }
+    cells.extend((0..rows).rev().map(|y| (0, y)));
+    cells
+}
+
+// The board really has no cycle
+fn no_cycle(reason: String) -> GameError {
+    GameError::GameState(format!("no Hamiltonian cycle: {}", reason))
+}
+
+// There may be a cycle, but not one this construction can build
+fn unsupported(reason: String) -> GameError {
+    GameError::GameState(format!("can't build a Hamiltonian cycle on this board: {}", reason))
+}
@@ -0,0 +1,37 @@
+mod view;
+mod greedy;
+mod astar;
+mod hamiltonian;
+mod bench;
+mod external;
+
+pub use view::GameView;
+pub use greedy::GreedyBot;
+pub use astar::AStarBot;
+pub use hamiltonian::{HamiltonianBot, hamiltonian_cycle};
+pub use bench::{BenchOptions, BenchReport, GameOutcome, play_game, bench_bot, summarize, format_table};
+pub use external::{ExternalBot, BotState, DEFAULT_MOVE_TIMEOUT};
+
+use crate::entities::Direction;
+
+pub const BOT_NAMES: [&str; 3] = ["greedy", "astar", "hamiltonian"];
+
+// Anything that can steer a snake. Bots are asked once per tick, just before the snake moves.
+pub trait Bot: Send {
+    fn name(&self) -> &'static str;
+    fn next_direction(&mut self, view: &GameView) -> Direction;
+
+    // Something the player should know about how the bot is playing
+    fn warning(&self) -> Option<String> {
+        None
+    }
+}
+
+pub fn bot_by_name(name: &str) -> Option<Box<dyn Bot>> {
+    match name.to_lowercase().as_str() {
+        "greedy" => Some(Box::new(GreedyBot::new())),
+        "astar" => Some(Box::new(AStarBot::new())),
+        "hamiltonian" => Some(Box::new(HamiltonianBot::new())),
+        _ => None,
+    }
+}
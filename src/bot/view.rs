@@ -0,0 +1,102 @@
+// src/bot/view.rs
+use std::collections::VecDeque;
+use crate::{
+    core::{CollisionManager, GameState},
+    entities::{Direction, Obstacle, Point},
+    gameplay::WallMode,
+    config::BORDER_THICKNESS,
+};
+
+// What a bot gets to see of the game: one player's snake and the board around it
+pub struct GameView<'a> {
+    game: &'a GameState,
+    player: usize,
+    collision_manager: CollisionManager,
+}
+
+impl<'a> GameView<'a> {
+    pub fn new(game: &'a GameState) -> Self {
+        Self::for_player(game, 0)
+    }
+
+    pub fn for_player(game: &'a GameState, player: usize) -> Self {
+        let (width, height) = game.dimensions();
+        Self {
+            game,
+            player,
+            collision_manager: CollisionManager::new(width, height),
+        }
+    }
+
+    // Tail first, head last
+    pub fn body(&self) -> &'a VecDeque<Point> {
+        self.game.players()[self.player].snake().body()
+    }
+
+    pub fn head(&self) -> Point {
+        self.body().back().copied().unwrap_or(Point::new(0, 0))
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.game.players()[self.player].snake().direction()
+    }
+
+    pub fn food(&self) -> Point { *self.game.food().position() }
+    pub fn obstacles(&self) -> &'a [Obstacle] { self.game.obstacles() }
+    pub fn dimensions(&self) -> (u16, u16) { self.game.dimensions() }
+    pub fn wall_mode(&self) -> WallMode { self.game.wall_mode() }
+    pub fn game(&self) -> &'a GameState { self.game }
+    pub fn player(&self) -> usize { self.player }
+
+    // Where a step from `point` lands, following wrap-around walls
+    pub fn step(&self, point: &Point, direction: Direction) -> Point {
+        let next = point.translate(&direction);
+        if self.wall_mode() == WallMode::Wrap && self.collision_manager.is_wall_collision(&next) {
+            self.collision_manager.wrap(&next)
+        } else {
+            next
+        }
+    }
+
+    // Walls and obstacles, which never move
+    pub fn is_wall(&self, point: &Point) -> bool {
+        self.collision_manager.is_wall_collision(point) ||
+        self.collision_manager.is_obstacle_collision(point, self.obstacles())
+    }
+
+    // Walls, obstacles and every snake still on the board, as they are right now
+    pub fn is_blocked(&self, point: &Point) -> bool {
+        self.is_wall(point) ||
+        self.game.players().iter()
+            .filter(|player| player.is_alive())
+            .any(|player| player.snake().body().contains(point))
+    }
+
+    // Everything in the way except this player's own body, which moves along with it
+    pub fn is_blocked_by_others(&self, point: &Point) -> bool {
+        self.is_wall(point) ||
+        self.game.players().iter().enumerate()
+            .filter(|(index, player)| *index != self.player && player.is_alive())
+            .any(|(_, player)| player.snake().body().contains(point))
+    }
+
+    // Fewest steps between two squares on an empty board, counting wrap-around
+    pub fn distance(&self, a: &Point, b: &Point) -> u32 {
+        let (width, height) = self.dimensions();
+        let (dx, dy) = (a.x.abs_diff(b.x), a.y.abs_diff(b.y));
+        let (dx, dy) = match self.wall_mode() {
+            WallMode::Wrap => (
+                dx.min((width - 2 * BORDER_THICKNESS).saturating_sub(dx)),
+                dy.min((height - 2 * BORDER_THICKNESS).saturating_sub(dy)),
+            ),
+            WallMode::Solid => (dx, dy),
+        };
+        u32::from(dx) + u32::from(dy)
+    }
+
+    // Squares one step from `point` in each direction, with the direction taken
+    pub fn neighbours(&self, point: &Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        let point = *point;
+        Direction::ALL.into_iter().map(move |direction| (direction, self.step(&point, direction)))
+    }
+}
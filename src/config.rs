@@ -1,15 +1,27 @@
 // config.rs
+use crate::{
+    gameplay::{GameMode, WallMode, Objective},
+    utils::Result,
+};
+use serde::Deserialize;
 
 // Game dimensions and timing
 pub const WIDTH: u16 = 50;
 pub const HEIGHT: u16 = 25;
 pub const BORDER_THICKNESS: u16 = 2;
+pub const MIN_BOARD_WIDTH: u16 = 20;
+pub const MIN_BOARD_HEIGHT: u16 = 12;
 
 // Speed settings
 pub const BASE_TICK_RATE: u64 = 200;
 pub const SPEED_DECREASE_PER_LEVEL: u64 = 10;
 pub const MIN_SPEED: u64 = 50;
 pub const BASE_SPEED_LEVEL: u32 = 1;
+pub const MAX_QUEUED_TURNS: usize = 3;
+pub const MAX_PENDING_EVENTS: usize = 1024;  // Undrained events beyond this drop the oldest
+
+// Local multiplayer
+pub const MAX_PLAYERS: usize = 4;
 
 // Level settings
 pub const STARTING_LEVEL: u32 = 1;
@@ -21,7 +33,45 @@ pub const BASE_OBSTACLES: u32 = 4;
 pub const OBSTACLES_PER_LEVEL: u32 = 2;
 pub const OBSTACLE_SIZES: [u16; 2] = [1, 2];
 
+// Endless mode settings
+pub const ENDLESS_SPEED_DECREASE_PER_LEVEL: u64 = 5;
+pub const ENDLESS_MIN_SPEED: u64 = 30;
+pub const ENDLESS_MAX_OBSTACLE_PERCENT: u32 = 30;  // Share of the playable area obstacles may cover
+pub const SPAWN_CLEARANCE: u16 = 5;  // Squares kept free ahead of each snake at the start of an endless level
+
+// Difficulty presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+    Insane,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Insane,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Insane => "Insane",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.name().eq_ignore_ascii_case(name))
+    }
+}
+
 // For code that expects a Config struct
+#[derive(Clone)]
 pub struct Config {
     pub width: u16,
     pub height: u16,
@@ -32,6 +82,15 @@ pub struct Config {
     pub base_obstacles: u32,
     pub obstacles_per_level: u32,
     pub obstacle_sizes: Vec<u16>,
+    pub mode: GameMode,
+    pub difficulty: Difficulty,
+    pub min_tick_rate_ms: u64,
+    pub wall_mode: WallMode,
+    pub score_multiplier: u32,
+    pub level_objectives: Vec<Objective>,  // Empty means every level uses the score target
+    pub exit_gate: bool,                   // Finish levels by reaching an exit instead of instantly
+    pub seed: Option<u64>,                 // Fixed seed for reproducible food and levels
+    pub players: usize,                    // Snakes sharing the board, 1 to MAX_PLAYERS
 }
 
 impl Config {
@@ -46,6 +105,126 @@ impl Config {
             base_obstacles: BASE_OBSTACLES,
             obstacles_per_level: OBSTACLES_PER_LEVEL,
             obstacle_sizes: OBSTACLE_SIZES.to_vec(),
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            min_tick_rate_ms: MIN_SPEED,
+            wall_mode: WallMode::Solid,
+            score_multiplier: 1,
+            level_objectives: Vec::new(),
+            exit_gate: false,
+            seed: None,
+            players: 1,
+        }
+    }
+
+    // Shrinks the board to fit a terminal, leaving a row for the status bar
+    pub fn fit_to_terminal(&mut self, columns: u16, rows: u16) {
+        self.width = self.width.min(columns).max(MIN_BOARD_WIDTH);
+        self.height = self.height.min(rows.saturating_sub(1)).max(MIN_BOARD_HEIGHT);
+    }
+
+    pub fn from_difficulty(difficulty: Difficulty) -> Self {
+        let base = Self::new();
+        match difficulty {
+            Difficulty::Easy => Self {
+                width: 40,
+                height: 20,
+                tick_rate_ms: 250,
+                min_tick_rate_ms: 80,
+                base_obstacles: 2,
+                obstacles_per_level: 1,
+                wall_mode: WallMode::Wrap,
+                difficulty,
+                ..base
+            },
+            Difficulty::Normal => base,
+            Difficulty::Hard => Self {
+                width: 56,
+                height: 28,
+                tick_rate_ms: 150,
+                min_tick_rate_ms: 40,
+                base_obstacles: 6,
+                obstacles_per_level: 3,
+                score_multiplier: 2,
+                difficulty,
+                ..base
+            },
+            Difficulty::Insane => Self {
+                width: 60,
+                height: 30,
+                tick_rate_ms: 110,
+                min_tick_rate_ms: 30,
+                base_obstacles: 10,
+                obstacles_per_level: 4,
+                score_multiplier: 3,
+                difficulty,
+                ..base
+            },
         }
     }
-}
\ No newline at end of file
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Optional user settings read from snake.toml (or .json/.yaml) next to the game
+pub const SETTINGS_FILE: &str = "snake";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Settings {
+    #[serde(default)]
+    pub theme: ThemeSettings,
+    #[serde(default)]
+    pub keys: KeySettings,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ThemeSettings {
+    pub name: Option<String>,
+    pub snake_gradient: Option<Vec<String>>,
+    pub snake: Option<StyleOverride>,
+    pub wall: Option<StyleOverride>,
+    pub obstacle: Option<StyleOverride>,
+    pub food: Option<StyleOverride>,
+    pub pickup: Option<StyleOverride>,
+    pub gate: Option<StyleOverride>,
+    pub status: Option<StyleOverride>,
+    pub dialog: Option<StyleOverride>,
+    pub hint: Option<StyleOverride>,
+}
+
+// Key names per action, e.g. `move_up = ["k", "up"]`
+#[derive(Debug, Default, Deserialize)]
+pub struct KeySettings {
+    pub preset: Option<String>,
+    pub move_up: Option<Vec<String>>,
+    pub move_down: Option<Vec<String>>,
+    pub move_left: Option<Vec<String>>,
+    pub move_right: Option<Vec<String>>,
+    pub pause: Option<Vec<String>>,
+    pub confirm: Option<Vec<String>>,
+    pub quit: Option<Vec<String>>,
+    pub achievements: Option<Vec<String>>,
+    pub hint: Option<Vec<String>>,
+    pub players: Option<Vec<Vec<String>>>,  // Up, down, left and right for each player in a match
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StyleOverride {
+    pub glyph: Option<char>,
+    pub fg: Option<String>,
+    pub bg: Option<String>,
+}
+
+impl Settings {
+    pub fn load(name: &str) -> Result<Self> {
+        let settings = ::config::Config::builder()
+            .add_source(::config::File::with_name(name).required(false))
+            .build()?
+            .try_deserialize()?;
+        Ok(settings)
+    }
+}
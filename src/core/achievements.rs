@@ -0,0 +1,156 @@
+use crate::{
+    utils::Result,
+    gameplay::GameEvent,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fs,
+    io::ErrorKind,
+    path::Path,
+};
+
+pub const ACHIEVEMENT_FILE: &str = "snake_achievements.json";
+const RECENT_FOOD_HISTORY: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    FoodEaten(u32),
+    ReachLength(usize),
+    FoodWithin { count: usize, window_ms: u64 },
+    ClearLevelWithinTurns { level: u32, max_turns: u32 },
+    WinGame,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Achievement {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub condition: Condition,
+}
+
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "first_bite",
+        name: "First Bite",
+        description: "Eat your first food",
+        condition: Condition::FoodEaten(1),
+    },
+    Achievement {
+        id: "feeding_frenzy",
+        name: "Feeding Frenzy",
+        description: "Eat 5 food in 10 seconds",
+        condition: Condition::FoodWithin { count: 5, window_ms: 10_000 },
+    },
+    Achievement {
+        id: "long_haul",
+        name: "Long Haul",
+        description: "Reach length 40",
+        condition: Condition::ReachLength(40),
+    },
+    Achievement {
+        id: "steady_hands",
+        name: "Steady Hands",
+        description: "Clear level 3 in 50 turns or fewer",
+        condition: Condition::ClearLevelWithinTurns { level: 3, max_turns: 50 },
+    },
+    Achievement {
+        id: "champion",
+        name: "Champion",
+        description: "Complete every classic level",
+        condition: Condition::WinGame,
+    },
+];
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Unlocked {
+    ids: BTreeSet<String>,
+}
+
+// Watches game events and unlocks achievements whose condition is met
+#[derive(Debug, Default)]
+pub struct AchievementTracker {
+    unlocked: Unlocked,
+    food_eaten: u32,
+    turns_this_level: u32,
+    recent_food: VecDeque<u64>,
+}
+
+impl AchievementTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let unlocked = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Unlocked::default(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { unlocked, ..Self::default() })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(&self.unlocked)?)?;
+        Ok(())
+    }
+
+    // Returns the achievements this event unlocked for the first time
+    pub fn process(&mut self, event: &GameEvent) -> Vec<&'static Achievement> {
+        let mut cleared_level = None;
+
+        match *event {
+            GameEvent::Turned { .. } => self.turns_this_level += 1,
+            GameEvent::FoodEaten { at_ms, .. } => {
+                self.food_eaten += 1;
+                self.recent_food.push_back(at_ms);
+                if self.recent_food.len() > RECENT_FOOD_HISTORY {
+                    self.recent_food.pop_front();
+                }
+            }
+            GameEvent::LevelComplete { level, .. } => cleared_level = Some(level),
+            GameEvent::GateOpened { .. } | GameEvent::Died { .. } | GameEvent::Victory { .. } => {}
+        }
+
+        let unlocked: Vec<_> = ACHIEVEMENTS.iter()
+            .filter(|a| !self.is_unlocked(a.id))
+            .filter(|a| self.is_met(&a.condition, event, cleared_level))
+            .collect();
+
+        for achievement in &unlocked {
+            self.unlocked.ids.insert(achievement.id.to_string());
+        }
+
+        if cleared_level.is_some() {
+            self.turns_this_level = 0;
+        }
+
+        unlocked
+    }
+
+    fn is_met(&self, condition: &Condition, event: &GameEvent, cleared_level: Option<u32>) -> bool {
+        match *condition {
+            Condition::FoodEaten(count) => self.food_eaten >= count,
+            Condition::ReachLength(target) => {
+                matches!(event, GameEvent::FoodEaten { length, .. } if *length >= target)
+            }
+            Condition::FoodWithin { count, window_ms } => {
+                let Some(&latest) = self.recent_food.back() else { return false };
+                self.recent_food.iter().filter(|&&t| latest - t <= window_ms).count() >= count
+            }
+            Condition::ClearLevelWithinTurns { level, max_turns } => {
+                cleared_level == Some(level) && self.turns_this_level <= max_turns
+            }
+            Condition::WinGame => matches!(event, GameEvent::Victory { .. }),
+        }
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.ids.contains(id)
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.ids.len()
+    }
+}
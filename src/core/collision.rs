@@ -22,6 +22,29 @@ impl CollisionManager {
         point.y >= self.dimensions.1 - BORDER_THICKNESS
     }
 
+    // Maps a point that left the playable area back in from the opposite side
+    pub fn wrap(&self, point: &Point) -> Point {
+        let max_x = self.dimensions.0 - BORDER_THICKNESS - 1;
+        let max_y = self.dimensions.1 - BORDER_THICKNESS - 1;
+
+        let x = if point.x < BORDER_THICKNESS {
+            max_x
+        } else if point.x > max_x {
+            BORDER_THICKNESS
+        } else {
+            point.x
+        };
+        let y = if point.y < BORDER_THICKNESS {
+            max_y
+        } else if point.y > max_y {
+            BORDER_THICKNESS
+        } else {
+            point.y
+        };
+
+        Point::new(x, y)
+    }
+
     pub fn is_self_collision(&self, snake: &VecDeque<Point>) -> bool {
         if let Some(head) = snake.back() {
             snake.iter().take(snake.len() - 1).any(|p| p == head)
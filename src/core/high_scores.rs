@@ -0,0 +1,67 @@
+use crate::{
+    utils::Result,
+    config::Difficulty,
+    gameplay::GameMode,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io::ErrorKind, path::Path};
+
+pub const HIGH_SCORE_FILE: &str = "snake_highscores.json";
+const ENTRIES_PER_TABLE: usize = 5;
+
+// Best scores kept separately for every preset and mode combination
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HighScores {
+    tables: BTreeMap<String, Vec<u32>>,
+}
+
+impl HighScores {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn key(difficulty: Difficulty, mode: GameMode) -> String {
+        match mode {
+            GameMode::Classic => difficulty.name().to_lowercase(),
+            GameMode::Endless => format!("{}-endless", difficulty.name().to_lowercase()),
+        }
+    }
+
+    // Campaign runs have objectives of their own, so they get their own tables
+    pub fn campaign_key(difficulty: Difficulty, mode: GameMode) -> String {
+        format!("{}-campaign", Self::key(difficulty, mode))
+    }
+
+    // Returns true when the score beats the previous best for this table
+    pub fn record(&mut self, key: &str, score: u32) -> bool {
+        let is_best = self.best(key).map_or(score > 0, |best| score > best);
+
+        let table = self.tables.entry(key.to_string()).or_default();
+        table.push(score);
+        table.sort_unstable_by(|a, b| b.cmp(a));
+        table.truncate(ENTRIES_PER_TABLE);
+
+        is_best
+    }
+
+    pub fn best(&self, key: &str) -> Option<u32> {
+        self.top(key).first().copied()
+    }
+
+    pub fn top(&self, key: &str) -> &[u32] {
+        self.tables.get(key).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
@@ -1,7 +1,13 @@
 mod state;
 mod collision;
 mod scoring;
+mod high_scores;
+mod achievements;
+mod player;
 
 pub use state::GameState;
+pub use player::Player;
 pub use collision::CollisionManager;
-pub use scoring::ScoreManager;
\ No newline at end of file
+pub use scoring::ScoreManager;
+pub use high_scores::{HighScores, HIGH_SCORE_FILE};
+pub use achievements::{Achievement, AchievementTracker, Condition, ACHIEVEMENTS, ACHIEVEMENT_FILE};
@@ -0,0 +1,48 @@
+// src/core/player.rs
+use std::collections::VecDeque;
+use crate::{
+    entities::Direction,
+    gameplay::{Snake, DeathCause},
+    config::MAX_QUEUED_TURNS,
+};
+use super::ScoreManager;
+
+// A snake and everything that belongs to whoever is steering it
+pub struct Player {
+    pub(super) snake: Snake,
+    pub(super) score_manager: ScoreManager,
+    pub(super) turns: VecDeque<Direction>,
+    pub(super) alive: bool,
+    pub(super) death: Option<DeathCause>,
+    pub(super) wait_ms: u64,  // Time banked towards this snake's next move
+}
+
+impl Player {
+    pub fn new(snake: Snake) -> Self {
+        Self {
+            snake,
+            score_manager: ScoreManager::new(),
+            turns: VecDeque::new(),
+            alive: true,
+            death: None,
+            wait_ms: 0,
+        }
+    }
+
+    // Turns made between two moves are applied one per move, so a quick
+    // up-then-left isn't lost, or turned into a reversal into the neck
+    pub fn queue_turn(&mut self, direction: Direction) {
+        let last = self.turns.back().copied().unwrap_or(self.snake.direction());
+        if direction == last || direction == last.opposite() || self.turns.len() >= MAX_QUEUED_TURNS {
+            return;
+        }
+        self.turns.push_back(direction);
+    }
+
+    pub fn snake(&self) -> &Snake { &self.snake }
+    pub fn score(&self) -> u32 { self.score_manager.score() }
+    pub fn speed_level(&self) -> u32 { self.score_manager.speed_level() }
+    pub fn is_alive(&self) -> bool { self.alive }
+    pub fn death_cause(&self) -> Option<DeathCause> { self.death }
+    pub fn queued_turns(&self) -> &VecDeque<Direction> { &self.turns }
+}
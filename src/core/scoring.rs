@@ -27,4 +27,10 @@ impl ScoreManager {
     pub fn speed_level(&self) -> u32 {
         self.speed_level
     }
-}
\ No newline at end of file
+}
+
+impl Default for ScoreManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -6,161 +6,521 @@ use crate::{
         GameState as GameStateEnum, 
         GameEndReason, 
         Snake, 
+        GameMode,
+        WallMode,
+        GameEvent,
+        Objective,
+        ObjectiveProgress,
         LevelState,
-        get_level_pattern
+        DeathCause,
+        get_classic_pattern,
+        get_endless_pattern,
     },
     config::*,
 };
 use log::debug;
+use std::collections::VecDeque;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use super::Player;
+
+// Random squares tried before placement falls back to scanning the board
+const PLACEMENT_TRIES: u32 = 100;
 
 pub struct GameState {
-    snake: Snake,
+    players: Vec<Player>,  // Player 0 is the one single-player code and achievements follow
     food: Food,
-    score_manager: super::ScoreManager,
     collision_manager: super::CollisionManager,
     dimensions: (u16, u16),
     state: GameStateEnum,
     obstacles: Vec<Obstacle>,
     level_state: LevelState,
     transition_message: String,
+    config: Config,
+    events: Vec<GameEvent>,
+    elapsed_ms: u64,
+    level_elapsed_ms: u64,
+    level_moves: u32,
+    pickups: Vec<Point>,
+    pickups_collected: u32,
+    exit_gate: Option<Point>,
+    rng: StdRng,
 }
 
 impl GameState {
     pub fn new() -> Self {
-        let dimensions = (WIDTH, HEIGHT);
-        let level_state = LevelState::new(
-            STARTING_LEVEL,
-            MAX_LEVELS,
-            SCORE_PER_LEVEL
-        );
+        Self::with_config(Config::new())
+    }
+
+    pub fn with_config(mut config: Config) -> Self {
+        // A list of objectives defines the whole campaign
+        if config.mode == GameMode::Classic && !config.level_objectives.is_empty() {
+            config.max_levels = config.level_objectives.len() as u32;
+        }
+
+        // Matches are played on one level, first to the target or last snake standing
+        config.players = config.players.clamp(1, MAX_PLAYERS);
+        if config.players > 1 {
+            config.level_objectives.clear();
+            config.exit_gate = false;
+        }
+
+        let dimensions = (config.width, config.height);
+        // Targets are scaled so the multiplier doesn't make levels shorter
+        let score_per_level = config.score_per_level * config.score_multiplier;
+        let level_state = match config.mode {
+            GameMode::Classic => LevelState::new(
+                config.starting_level,
+                config.max_levels,
+                score_per_level
+            ),
+            GameMode::Endless => LevelState::endless(
+                config.starting_level,
+                score_per_level
+            ),
+        };
 
         debug!("Initializing game with dimensions: {}x{}", dimensions.0, dimensions.1);
-        debug!("Level settings - Start: {}, Max: {}, Score per level: {}", 
-            STARTING_LEVEL, MAX_LEVELS, SCORE_PER_LEVEL);
+        debug!("Difficulty: {}, Walls: {:?}, Score multiplier: {}",
+            config.difficulty.name(), config.wall_mode, config.score_multiplier);
+        debug!("Level settings - Mode: {:?}, Start: {}, Max: {}, Score per level: {}", 
+            config.mode, config.starting_level, config.max_levels, config.score_per_level);
 
         let mut state = Self {
-            snake: Snake::new(WIDTH / 10, HEIGHT / 10),  // Changed to start at 1/10th of screen
+            players: Self::start_snakes(dimensions, config.players).into_iter().map(Player::new).collect(),
             food: Food::new(Point::new(0, 0)),
-            score_manager: super::ScoreManager::new(),
-            collision_manager: super::CollisionManager::new(WIDTH, HEIGHT),
+            collision_manager: super::CollisionManager::new(dimensions.0, dimensions.1),
             dimensions,
             state: GameStateEnum::Playing,
             obstacles: Vec::new(),
             level_state,
             transition_message: String::new(),
+            events: Vec::new(),
+            elapsed_ms: 0,
+            level_elapsed_ms: 0,
+            level_moves: 0,
+            pickups: Vec::new(),
+            pickups_collected: 0,
+            exit_gate: None,
+            rng: match config.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            },
+            config,
         };
 
         state.reset_level();
         state
     }
 
+    // Start at 1/10th of the board, but never inside the border on small boards.
+    // Other players start in the remaining corners, facing inwards.
+    fn start_snakes(dimensions: (u16, u16), count: usize) -> Vec<Snake> {
+        let left = (dimensions.0 / 10).max(BORDER_THICKNESS + 2);
+        let top = (dimensions.1 / 10).max(BORDER_THICKNESS);
+        let right = dimensions.0 - 1 - left;
+        let bottom = dimensions.1 - 1 - top;
+
+        [
+            (left, top, Direction::Right),
+            (right, bottom, Direction::Left),
+            (left, bottom, Direction::Right),
+            (right, top, Direction::Left),
+        ]
+        .into_iter()
+        .take(count)
+        .map(|(x, y, direction)| Snake::with_direction(x, y, direction))
+        .collect()
+    }
+
     fn reset_level(&mut self) {
         debug!("Resetting level {}", self.level_state.current_level);
-        let center_x = self.dimensions.0 / 10;
-        let center_y = self.dimensions.1 / 10;
-        
-        self.snake = Snake::new(center_x, center_y);
-        self.score_manager.reset_speed();
+        let snakes = Self::start_snakes(self.dimensions, self.players.len());
+        for (player, snake) in self.players.iter_mut().zip(snakes) {
+            player.snake = snake;
+            player.turns.clear();
+            player.score_manager.reset_speed();
+            player.wait_ms = 0;
+        }
 
         // Generate new obstacles using pattern
-        let pattern = get_level_pattern(
-            self.level_state.current_level,
-            self.dimensions.0,
-            self.dimensions.1
-        );
+        let pattern = match self.config.mode {
+            GameMode::Classic => get_classic_pattern(
+                self.level_state.current_level,
+                &self.config
+            ),
+            GameMode::Endless => get_endless_pattern(
+                self.level_state.current_level,
+                &self.config,
+                &mut self.rng
+            ),
+        };
 
+        // Generated levels can drop a block right in front of a snake; the
+        // hand-made classic layouts are left as they are
+        let clearance = self.spawn_clearance();
         self.obstacles = pattern.positions.iter().zip(pattern.sizes.iter())
             .map(|((x, y), (w, h))| Obstacle::new_rectangle(Point::new(*x, *y), *w, *h))
+            .filter(|obstacle| !obstacle.blocks.iter().any(|p| clearance.contains(p)))
             .collect();
 
         debug!("Generated {} obstacles for level {}", self.obstacles.len(), self.level_state.current_level);
 
         // Generate food in valid position
+        self.pickups.clear();
+        self.exit_gate = None;
         self.generate_new_food();
+
+        self.level_elapsed_ms = 0;
+        self.level_moves = 0;
+        self.pickups_collected = 0;
+        for _ in 0..self.objective().pickup_count() {
+            let (food, placed) = (*self.food.position(), self.pickups.clone());
+            // A crowded board gets as many as fit
+            let Some(pickup) = self.random_free_square(|point| *point != food && !placed.contains(point)) else {
+                break;
+            };
+            self.pickups.push(pickup);
+        }
+    }
+
+    // Squares just ahead of each snake's head that an endless level keeps free
+    fn spawn_clearance(&self) -> Vec<Point> {
+        if self.config.mode != GameMode::Endless {
+            return Vec::new();
+        }
+        self.players.iter()
+            .filter_map(|player| player.snake.head().map(|head| (*head, player.snake.direction())))
+            .flat_map(|(head, direction)| {
+                std::iter::successors(Some(head), move |point| Some(point.translate(&direction)))
+                    .skip(1)
+                    .take(usize::from(SPAWN_CLEARANCE))
+            })
+            .collect()
+    }
+
+    fn occupied_by_snake(players: &[Player], point: &Point) -> bool {
+        players.iter().any(|player| player.alive && player.snake.body().contains(point))
+    }
+
+    // Whether any square is left for food to appear on
+    fn has_free_square(&self) -> bool {
+        let (width, height) = self.dimensions;
+
+        // Cheap count first; obstacles may overlap, so only a near-full board is scanned
+        let area = usize::from(width - 2 * BORDER_THICKNESS) * usize::from(height - 2 * BORDER_THICKNESS);
+        let snakes: usize = self.players.iter().filter(|p| p.alive).map(|p| p.snake.body().len()).sum();
+        let obstacles: usize = self.obstacles.iter().map(|o| o.blocks.len()).sum();
+        if snakes + obstacles < area {
+            return true;
+        }
+
+        self.free_squares().next().is_some()
     }
 
     fn generate_new_food(&mut self) {
-        self.food = Food::generate_new(
-            self.dimensions.0,
-            self.dimensions.1,
-            |point| {
-                !self.snake.body().contains(point) && 
-                !self.collision_manager.is_obstacle_collision(point, &self.obstacles)
+        // A full board has nowhere to put it; the tick ends the game instead.
+        // Pickups and the exit gate are drawn over food, so it never goes under them.
+        let (pickups, gate) = (self.pickups.clone(), self.exit_gate);
+        let Some(position) = self.random_free_square(|point| !pickups.contains(point) && Some(*point) != gate) else {
+            return;
+        };
+        self.food = Food::new(position);
+        debug!("New food generated at position: ({}, {})", position.x, position.y);
+    }
+
+    // A free square that also passes `is_valid`, or None if there's none left.
+    // Random tries are quick on an open board; a crowded one gets scanned.
+    fn random_free_square(&mut self, is_valid: impl Fn(&Point) -> bool) -> Option<Point> {
+        let (width, height) = self.dimensions;
+        for _ in 0..PLACEMENT_TRIES {
+            let point = Point::new(
+                self.rng.gen_range(BORDER_THICKNESS..width - BORDER_THICKNESS),
+                self.rng.gen_range(BORDER_THICKNESS..height - BORDER_THICKNESS),
+            );
+            if self.is_free(&point) && is_valid(&point) {
+                return Some(point);
             }
-        );
-        debug!("New food generated at position: ({}, {})", 
-            self.food.position().x, 
-            self.food.position().y);
+        }
+
+        let candidates: Vec<Point> = self.free_squares().filter(|point| is_valid(point)).collect();
+        candidates.choose(&mut self.rng).copied()
     }
 
+    // Advances the game one tick. Events pile up until `drain_events` is
+    // called; only the latest MAX_PENDING_EVENTS are kept if nobody does.
     pub fn update(&mut self) -> Result<()> {
-        match self.state {
+        let result = match self.state {
             GameStateEnum::Playing => self.update_playing(),
+            GameStateEnum::Paused => Ok(()),
             GameStateEnum::LevelTransition => Ok(()),
             GameStateEnum::GameOver(_) => Ok(()),
-        }
+        };
+
+        let excess = self.events.len().saturating_sub(MAX_PENDING_EVENTS);
+        self.events.drain(..excess);
+        result
     }
 
     fn update_playing(&mut self) -> Result<()> {
-        let next_head = self.snake.next_head_position()
-            .ok_or_else(|| GameError::GameState("Snake has no head".to_string()))?;
+        let tick = self.get_tick_rate();
+        self.elapsed_ms += tick;
+        self.level_elapsed_ms += tick;
+
+        // Snakes on slower speeds sit out some ticks; a lone snake moves every tick
+        let mut movers = Vec::new();
+        for (index, player) in self.players.iter_mut().enumerate() {
+            if !player.alive {
+                continue;
+            }
+            player.wait_ms += tick;
+            let rate = Self::tick_rate_for(&self.config, &self.level_state, player.speed_level());
+            if player.wait_ms >= rate {
+                player.wait_ms -= rate;
+                movers.push(index);
+            }
+        }
+
+        let mut next_heads = Vec::with_capacity(movers.len());
+        for &index in &movers {
+            if let Some(direction) = self.players[index].turns.pop_front() {
+                self.turn_player(index, direction);
+            }
+
+            let mut next_head = self.players[index].snake.next_head_position()
+                .ok_or_else(|| GameError::GameState("Snake has no head".to_string()))?;
+            if self.config.wall_mode == WallMode::Wrap && self.collision_manager.is_wall_collision(&next_head) {
+                next_head = self.collision_manager.wrap(&next_head);
+            }
+            next_heads.push(next_head);
+        }
+
+        // Check collisions first. All snakes move at once, so two heads
+        // meeting (or swapping places) take both snakes out.
+        let crashes: Vec<Option<DeathCause>> = movers.iter().zip(&next_heads)
+            .map(|(&index, next_head)| self.crash_cause(index, next_head, &movers, &next_heads))
+            .collect();
+        let crashed: Vec<bool> = crashes.iter().map(Option::is_some).collect();
+
+        for (&index, cause) in movers.iter().zip(&crashes) {
+            let Some(cause) = *cause else {
+                continue;
+            };
+            debug!("Player {} hit {}", index + 1, cause.name());
+            self.players[index].alive = false;
+            self.players[index].death = Some(cause);
+            if index == 0 {
+                self.events.push(GameEvent::Died {
+                    level: self.level_state.current_level,
+                    at_ms: self.elapsed_ms,
+                });
+            }
+        }
+
+        if self.players.len() > 1 {
+            for (i, &index) in movers.iter().enumerate() {
+                if !crashed[i] {
+                    self.move_player(index, next_heads[i], false);
+                }
+            }
+            self.check_match_over();
+            return Ok(());
+        }
 
-        // Check collisions first
-        if self.collision_manager.is_wall_collision(&next_head) || 
-           self.collision_manager.is_self_collision(self.snake.body()) ||
-           self.collision_manager.is_obstacle_collision(&next_head, &self.obstacles) {
+        if crashed.first() == Some(&true) {
             debug!("Collision detected - Game Over");
             self.state = GameStateEnum::GameOver(GameEndReason::Collision);
             return Ok(());
         }
+        let Some(&next_head) = next_heads.first() else {
+            return Ok(());
+        };
+
+        // Check if we will collect a pickup before moving
+        let will_collect_pickup = self.pickup_at(&next_head);
+        if let Some(index) = will_collect_pickup {
+            self.pickups.remove(index);
+            self.pickups_collected += 1;
+        }
+        self.move_player(0, next_head, will_collect_pickup.is_some());
+        self.level_moves += 1;
+
+        if !self.has_free_square() {
+            debug!("Board full - Game Over");
+            self.state = GameStateEnum::GameOver(GameEndReason::BoardFull);
+            self.events.push(GameEvent::Victory { at_ms: self.elapsed_ms });
+            return Ok(());
+        }
+
+        if self.exit_gate == Some(next_head) {
+            debug!("Snake reached the exit gate");
+            self.complete_level();
+        } else if self.exit_gate.is_some() {
+            // Target already met, waiting for the snake to reach the exit
+        } else if self.objective_progress().is_complete() {
+            if self.config.exit_gate {
+                self.open_exit_gate();
+            } else {
+                self.complete_level();
+            }
+        } else if self.objective_progress().moves_left == Some(0) {
+            debug!("Move budget exhausted - Game Over");
+            self.state = GameStateEnum::GameOver(GameEndReason::OutOfMoves);
+            self.events.push(GameEvent::Died {
+                level: self.level_state.current_level,
+                at_ms: self.elapsed_ms,
+            });
+        }
+
+        Ok(())
+    }
+
+    // What the snake at `index` runs into by moving to `next_head`, if anything
+    fn crash_cause(&self, index: usize, next_head: &Point, movers: &[usize], next_heads: &[Point]) -> Option<DeathCause> {
+        let hits_snake = self.players.iter().enumerate()
+            .any(|(other, player)| other != index && player.alive && player.snake.body().contains(next_head));
+        let head_on = movers.iter().zip(next_heads).any(|(&other, head)| other != index && head == next_head);
 
-        // Check if we will collect food before moving
+        if self.collision_manager.is_wall_collision(next_head) {
+            Some(DeathCause::Wall)
+        } else if self.collision_manager.is_self_collision(self.players[index].snake.body()) {
+            Some(DeathCause::Itself)
+        } else if self.collision_manager.is_obstacle_collision(next_head, &self.obstacles) {
+            Some(DeathCause::Obstacle)
+        } else if head_on {
+            Some(DeathCause::HeadOn)
+        } else if hits_snake {
+            Some(DeathCause::Snake)
+        } else {
+            None
+        }
+    }
+
+    // Moves a snake that survived this tick, growing it if it eats
+    fn move_player(&mut self, index: usize, next_head: Point, will_collect_pickup: bool) {
         let will_collect_food = next_head == *self.food.position();
 
-        // Move snake
-        self.snake.move_forward(next_head);
+        let player = &mut self.players[index];
+        player.snake.move_forward(next_head);
 
         // Handle food collection after movement
-        if will_collect_food {
-            self.score_manager.add_score(1);
-            debug!("Food collected! Score: {}, Level: {}", 
-                self.score_manager.score(), 
+        if will_collect_food || will_collect_pickup {
+            player.score_manager.add_score(self.config.score_multiplier);
+            debug!("Food collected by player {}! Score: {}, Level: {}", 
+                index + 1,
+                player.score_manager.score(), 
                 self.level_state.current_level);
-
-            // Check level advancement
-            if self.level_state.should_advance(self.score_manager.score()) {
-                debug!("Level {} complete! Advancing to next level", self.level_state.current_level);
-                self.level_state.advance();
-                self.prepare_next_level();
-                return Ok(());
+            if index == 0 {
+                self.events.push(GameEvent::FoodEaten {
+                    at_ms: self.elapsed_ms,
+                    length: player.snake.body().len(),
+                    score: player.score_manager.score(),
+                });
             }
 
-            // Check victory condition
-            if self.level_state.current_level == self.level_state.max_levels {
-                let final_score_needed = self.level_state.score_per_level * self.level_state.max_levels;
-                if self.score_manager.score() >= final_score_needed {
-                    debug!("Final level complete! Victory!");
-                    self.state = GameStateEnum::GameOver(GameEndReason::Victory);
-                    return Ok(());
-                }
+            if will_collect_food {
+                self.generate_new_food();
             }
+        } else {
+            player.snake.retract_tail();
+        }
+    }
+
+    // Index of the pickup the head would collect at this point, if any.
+    // Ordered pickups can only be collected in sequence.
+    fn pickup_at(&self, point: &Point) -> Option<usize> {
+        match self.objective() {
+            Objective::CollectInOrder(_) => (self.pickups.first() == Some(point)).then_some(0),
+            _ => self.pickups.iter().position(|p| p == point),
+        }
+    }
+
+    // Places the exit well away from the head so the snake has to travel to it.
+    // A crowded board may have no square that far, or none free at all.
+    fn open_exit_gate(&mut self) {
+        let head = *self.snake().head().unwrap_or(&Point::new(0, 0));
+        let min_distance = (self.dimensions.0 + self.dimensions.1) / 4;
+        let (food, pickups) = (*self.food.position(), self.pickups.clone());
+        let clear = |point: &Point| *point != food && !pickups.contains(point);
+
+        let gate = self.random_free_square(|point| {
+            clear(point) && head.x.abs_diff(point.x) + head.y.abs_diff(point.y) >= min_distance
+        }).or_else(|| self.random_free_square(clear));
+
+        let Some(gate) = gate else {
+            debug!("No room for the exit gate");
+            self.complete_level();
+            return;
+        };
+
+        self.exit_gate = Some(gate);
+        debug!("Exit gate opened at ({}, {})", gate.x, gate.y);
+        self.events.push(GameEvent::GateOpened { at_ms: self.elapsed_ms });
+    }
+
+    fn is_free(&self, point: &Point) -> bool {
+        !Self::occupied_by_snake(&self.players, point) &&
+        !self.collision_manager.is_obstacle_collision(point, &self.obstacles)
+    }
+
+    // Squares inside the walls with no snake or obstacle on them
+    fn free_squares(&self) -> impl Iterator<Item = Point> + '_ {
+        let (width, height) = self.dimensions;
+        (BORDER_THICKNESS..height - BORDER_THICKNESS)
+            .flat_map(move |y| (BORDER_THICKNESS..width - BORDER_THICKNESS).map(move |x| Point::new(x, y)))
+            .filter(|point| self.is_free(point))
+    }
 
-            // Generate new food
-            self.generate_new_food();
+    fn complete_level(&mut self) {
+        debug!("Level {} complete!", self.level_state.current_level);
+        self.events.push(GameEvent::LevelComplete {
+            level: self.level_state.current_level,
+            at_ms: self.elapsed_ms,
+        });
+
+        // Endless games only end on death
+        if self.level_state.is_final_level() {
+            debug!("Final level complete! Victory!");
+            self.state = GameStateEnum::GameOver(GameEndReason::Victory);
+            self.events.push(GameEvent::Victory { at_ms: self.elapsed_ms });
         } else {
-            self.snake.retract_tail();
+            debug!("Advancing to next level");
+            self.level_state.advance();
+            self.prepare_next_level();
         }
+    }
 
-        Ok(())
+    pub fn objective(&self) -> Objective {
+        let index = self.level_state.current_level as usize - 1;
+        self.config.level_objectives.get(index).copied().unwrap_or(Objective::Score)
+    }
+
+    pub fn objective_progress(&self) -> ObjectiveProgress {
+        let objective = self.objective();
+        let (current, target) = match objective {
+            Objective::Score => (
+                self.score(),
+                self.level_state.score_needed_for_current(),
+            ),
+            Objective::ReachLength(length) => (self.snake().body().len() as u32, length as u32),
+            Objective::CollectInOrder(count) => (self.pickups_collected, count),
+            Objective::Survive { seconds } => ((self.level_elapsed_ms / 1000) as u32, seconds),
+            Objective::CollectAll { count, .. } => (self.pickups_collected, count),
+        };
+
+        ObjectiveProgress {
+            current,
+            target,
+            moves_left: objective.move_budget().map(|moves| moves.saturating_sub(self.level_moves)),
+        }
     }
 
     fn prepare_next_level(&mut self) {
         debug!("Preparing level {} transition", self.level_state.current_level);
         self.state = GameStateEnum::LevelTransition;
         self.transition_message = format!(
-            "Level {} Complete!\nScore: {}\nPress SPACE to continue",
+            "Level {} Complete!\nScore: {}",
             self.level_state.current_level - 1,
-            self.score_manager.score()
+            self.score()
         );
     }
 
@@ -170,25 +530,136 @@ impl GameState {
         self.state = GameStateEnum::Playing;
     }
 
+    pub fn pause(&mut self) {
+        if self.state == GameStateEnum::Playing {
+            debug!("Game paused");
+            self.state = GameStateEnum::Paused;
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if self.state == GameStateEnum::Paused {
+            debug!("Game resumed");
+            self.state = GameStateEnum::Playing;
+        }
+    }
+
+    // The game ticks at the pace of the fastest snake still in play
     pub fn get_tick_rate(&self) -> u64 {
-        BASE_TICK_RATE.saturating_sub(
-            SPEED_DECREASE_PER_LEVEL * u64::from(self.score_manager.speed_level() - 1)
-        ).max(MIN_SPEED)
+        self.players.iter()
+            .filter(|player| player.alive)
+            .map(|player| Self::tick_rate_for(&self.config, &self.level_state, player.speed_level()))
+            .min()
+            .unwrap_or_else(|| Self::tick_rate_for(&self.config, &self.level_state, 1))
+    }
+
+    fn tick_rate_for(config: &Config, level_state: &LevelState, speed_level: u32) -> u64 {
+        let rate = config.tick_rate_ms.saturating_sub(
+            SPEED_DECREASE_PER_LEVEL * u64::from(speed_level - 1)
+        );
+
+        match config.mode {
+            GameMode::Classic => rate.max(config.min_tick_rate_ms),
+            // Each endless level also starts faster than the one before it
+            GameMode::Endless => rate.saturating_sub(
+                ENDLESS_SPEED_DECREASE_PER_LEVEL * u64::from(level_state.current_level - 1)
+            ).max(config.min_tick_rate_ms.min(ENDLESS_MIN_SPEED)),
+        }
     }
 
     pub fn change_direction(&mut self, new_direction: Direction) {
-        self.snake.change_direction(new_direction);
+        self.turn_player(0, new_direction);
+    }
+
+    fn turn_player(&mut self, index: usize, new_direction: Direction) {
+        let snake = &mut self.players[index].snake;
+        let old_direction = snake.direction();
+        snake.change_direction(new_direction);
+        if index == 0 && snake.direction() != old_direction {
+            self.events.push(GameEvent::Turned { at_ms: self.elapsed_ms });
+        }
+    }
+
+    pub fn queue_turn(&mut self, direction: Direction) {
+        self.queue_turn_for(0, direction);
+    }
+
+    pub fn queue_turn_for(&mut self, player: usize, direction: Direction) {
+        if let Some(player) = self.players.get_mut(player) {
+            player.queue_turn(direction);
+        }
+    }
+
+    pub fn queued_turns(&self) -> &VecDeque<Direction> {
+        self.players[0].queued_turns()
+    }
+
+    // Ends a match once one snake is left, someone reaches the target or the board fills up
+    fn check_match_over(&mut self) {
+        let alive: Vec<usize> = (0..self.players.len()).filter(|&i| self.players[i].alive).collect();
+        let target = self.match_target();
+        let best = self.players.iter().map(Player::score).max().unwrap_or(0);
+
+        let reason = if best >= target || alive.is_empty() || !self.has_free_square() {
+            self.leader()
+        } else if let [survivor] = alive.as_slice() {
+            GameEndReason::Winner(*survivor)
+        } else {
+            return;
+        };
+        debug!("Match over: {:?}", reason);
+        self.state = GameStateEnum::GameOver(reason);
+    }
+
+    // The player with the highest score, or a draw when the lead is shared
+    fn leader(&self) -> GameEndReason {
+        let best = self.players.iter().map(Player::score).max().unwrap_or(0);
+        let leaders: Vec<usize> = (0..self.players.len())
+            .filter(|&i| self.players[i].score() == best)
+            .collect();
+        match leaders.as_slice() {
+            [winner] => GameEndReason::Winner(*winner),
+            _ => GameEndReason::Draw,
+        }
+    }
+
+    // Score that wins a multiplayer match outright
+    pub fn match_target(&self) -> u32 {
+        self.config.score_per_level * self.config.score_multiplier * self.config.max_levels
+    }
+
+    // Hands over everything that happened since the last call, oldest first
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
     }
 
     // Getters
-    pub fn snake(&self) -> &Snake { &self.snake }
+    pub fn snake(&self) -> &Snake { self.players[0].snake() }
+    pub fn players(&self) -> &[Player] { &self.players }
+    pub fn death_cause(&self) -> Option<DeathCause> { self.players[0].death_cause() }
     pub fn food(&self) -> &Food { &self.food }
-    pub fn score(&self) -> u32 { self.score_manager.score() }
+    pub fn score(&self) -> u32 { self.players[0].score() }
     pub fn game_state(&self) -> GameStateEnum { self.state }
     pub fn obstacles(&self) -> &Vec<Obstacle> { &self.obstacles }
-    pub fn speed_level(&self) -> u32 { self.score_manager.speed_level() }
+    pub fn speed_level(&self) -> u32 { self.players[0].speed_level() }
     pub fn current_level(&self) -> u32 { self.level_state.current_level }
     pub fn max_levels(&self) -> u32 { self.level_state.max_levels }
+    pub fn mode(&self) -> GameMode { self.config.mode }
+    pub fn difficulty(&self) -> Difficulty { self.config.difficulty }
+    pub fn wall_mode(&self) -> WallMode { self.config.wall_mode }
+    pub fn dimensions(&self) -> (u16, u16) { self.dimensions }
+    pub fn elapsed_ms(&self) -> u64 { self.elapsed_ms }
+    pub fn level_elapsed_ms(&self) -> u64 { self.level_elapsed_ms }
+    pub fn score_multiplier(&self) -> u32 { self.config.score_multiplier }
+    pub fn uses_exit_gate(&self) -> bool { self.config.exit_gate }
     pub fn transition_message(&self) -> &str { &self.transition_message }
     pub fn score_needed_for_next(&self) -> Option<u32> { self.level_state.score_needed_for_next() }
-}
\ No newline at end of file
+    pub fn pickups(&self) -> &[Point] { &self.pickups }
+    pub fn exit_gate(&self) -> Option<&Point> { self.exit_gate.as_ref() }
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
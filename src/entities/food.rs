@@ -16,8 +16,15 @@ impl Food {
         height: u16, 
         is_position_valid: impl Fn(&Point) -> bool
     ) -> Self {
-        let mut rng = rand::thread_rng();
-        
+        Self::generate_with_rng(width, height, &mut rand::thread_rng(), is_position_valid)
+    }
+
+    pub fn generate_with_rng(
+        width: u16,
+        height: u16,
+        rng: &mut impl Rng,
+        is_position_valid: impl Fn(&Point) -> bool
+    ) -> Self {
         loop {
             let position = Point::new(
                 rng.gen_range(BORDER_THICKNESS..width - BORDER_THICKNESS),
@@ -0,0 +1,182 @@
+// src/env/mod.rs
+mod observation;
+mod vector;
+
+pub use observation::{Encoding, Observation, GRID_CHANNELS, FEATURE_COUNT, RAY_COUNT, RAY_VALUES};
+pub use vector::VecEnv;
+
+use crate::{
+    bot::GameView,
+    config::Config,
+    core::GameState,
+    entities::Direction,
+    gameplay::{DeathCause, GameEndReason, GameEvent, GameState as GameStateEnum},
+    utils::{Result, GameError},
+};
+
+// Observation, reward, done and info, as returned by `step`
+pub type Step = (Observation, f32, bool, StepInfo);
+
+// What each kind of event is worth to an agent
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rewards {
+    pub food: f32,            // Per food or pickup eaten
+    pub death: f32,           // Crashing, or running out of moves
+    pub step: f32,            // Every step, usually a small cost so stalling doesn't pay
+    pub approach: f32,        // Per square closer to the food, taken back when moving away
+    pub level_complete: f32,
+    pub victory: f32,         // Winning a classic game or filling the board
+}
+
+impl Default for Rewards {
+    fn default() -> Self {
+        Self {
+            food: 1.0,
+            death: -1.0,
+            step: -0.01,
+            approach: 0.0,
+            level_complete: 5.0,
+            victory: 10.0,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct EnvOptions {
+    pub config: Config,      // The rules; `seed` and `players` are set by the environment
+    pub encoding: Encoding,
+    pub rewards: Rewards,
+    pub max_steps: u64,      // Episodes still going after this many steps are cut off
+}
+
+impl Default for EnvOptions {
+    fn default() -> Self {
+        Self {
+            config: Config::new(),
+            encoding: Encoding::Features,
+            rewards: Rewards::default(),
+            max_steps: 10_000,
+        }
+    }
+}
+
+// Everything about a step that isn't part of the observation
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepInfo {
+    pub score: u32,
+    pub level: u32,
+    pub length: usize,
+    pub steps: u64,
+    pub events: Vec<GameEvent>,
+    pub end: Option<GameEndReason>,
+    pub death: Option<DeathCause>,
+    pub truncated: bool,                          // Stopped by `max_steps` rather than the game
+    pub final_observation: Option<Observation>,   // Set by `VecEnv` when it resets a finished episode
+}
+
+// A single-player game behind a gym-style interface. The game runs on the
+// real rules, levels included: a completed level moves straight on to the next.
+pub struct SnakeEnv {
+    options: EnvOptions,
+    game: GameState,
+    steps: u64,
+    done: bool,
+}
+
+impl SnakeEnv {
+    pub fn new(mut options: EnvOptions) -> Self {
+        options.config.players = 1;
+        let game = GameState::with_config(options.config.clone());
+        Self { options, game, steps: 0, done: false }
+    }
+
+    // Starts a new episode; the same seed always plays out the same way
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        let config = Config { seed: Some(seed), ..self.options.config.clone() };
+        self.game = GameState::with_config(config);
+        self.steps = 0;
+        self.done = false;
+        self.observe()
+    }
+
+    // Turns towards `action` (reversing is ignored, as in the game) and moves one square
+    pub fn step(&mut self, action: Direction) -> Result<Step> {
+        if self.done {
+            return Err(GameError::GameState("episode is over, call reset first".to_string()));
+        }
+
+        let distance_before = self.food_distance();
+        self.game.change_direction(action);
+        self.game.update()?;
+        self.steps += 1;
+
+        let events = self.game.drain_events();
+        if self.game.game_state() == GameStateEnum::LevelTransition {
+            self.game.start_next_level();
+        }
+
+        let rewards = self.options.rewards;
+        let mut reward = rewards.step;
+        for event in &events {
+            reward += match event {
+                GameEvent::FoodEaten { .. } => rewards.food,
+                GameEvent::Died { .. } => rewards.death,
+                GameEvent::LevelComplete { .. } => rewards.level_complete,
+                GameEvent::Victory { .. } => rewards.victory,
+                _ => 0.0,
+            };
+        }
+        // Eating moves the food and a new level moves the snake, so only compare ordinary steps
+        let moved_on = events.iter().any(|event| {
+            matches!(event, GameEvent::FoodEaten { .. } | GameEvent::LevelComplete { .. })
+        });
+        if !moved_on && self.game.game_state() == GameStateEnum::Playing {
+            reward += rewards.approach * (distance_before as f32 - self.food_distance() as f32);
+        }
+
+        let end = match self.game.game_state() {
+            GameStateEnum::GameOver(reason) => Some(reason),
+            _ => None,
+        };
+        let truncated = end.is_none() && self.steps >= self.options.max_steps;
+        self.done = end.is_some() || truncated;
+
+        let info = StepInfo {
+            score: self.game.score(),
+            level: self.game.current_level(),
+            length: self.game.snake().body().len(),
+            steps: self.steps,
+            events,
+            end,
+            death: self.game.death_cause(),
+            truncated,
+            final_observation: None,
+        };
+        Ok((self.observe(), reward, self.done, info))
+    }
+
+    pub fn observe(&self) -> Observation {
+        self.options.encoding.encode(&GameView::new(&self.game))
+    }
+
+    pub fn observation_shape(&self) -> Vec<usize> {
+        self.options.encoding.shape(self.game.dimensions())
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn game(&self) -> &GameState {
+        &self.game
+    }
+
+    pub fn options(&self) -> &EnvOptions {
+        &self.options
+    }
+
+    fn food_distance(&self) -> u32 {
+        let view = GameView::new(&self.game);
+        view.distance(&view.head(), &view.food())
+    }
+}
@@ -0,0 +1,191 @@
+// src/env/observation.rs
+use crate::{
+    bot::GameView,
+    config::BORDER_THICKNESS,
+    entities::{Direction, Point},
+};
+
+// Planes in the grid encoding, in this order
+pub const GRID_CHANNELS: usize = 7;
+pub const FEATURE_COUNT: usize = 16;
+pub const RAY_COUNT: usize = 8;
+// Each ray reports walls and obstacles, snake bodies and food
+pub const RAY_VALUES: usize = 3;
+
+const HEAD: usize = 0;
+const BODY: usize = 1;
+const FOOD: usize = 2;
+const OBSTACLE: usize = 3;
+const RIVAL: usize = 4;
+const PICKUP: usize = 5;
+const GATE: usize = 6;
+
+// Clockwise from straight up, as one or two steps per square
+const RAYS: [&[Direction]; RAY_COUNT] = [
+    &[Direction::Up],
+    &[Direction::Up, Direction::Right],
+    &[Direction::Right],
+    &[Direction::Down, Direction::Right],
+    &[Direction::Down],
+    &[Direction::Down, Direction::Left],
+    &[Direction::Left],
+    &[Direction::Up, Direction::Left],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// One-hot planes over the playable area: head, body, food, obstacles,
+    /// other snakes, pickups and the exit gate.
+    Grid,
+    /// Danger around the head, heading, where the food is and level progress.
+    Features,
+    /// Inverse distances to walls, bodies and food along eight rays from the head.
+    Rays,
+}
+
+// A flat tensor, row-major in `shape` order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation {
+    pub shape: Vec<usize>,
+    pub data: Vec<f32>,
+}
+
+impl Encoding {
+    pub fn shape(&self, dimensions: (u16, u16)) -> Vec<usize> {
+        let (width, height) = playable_size(dimensions);
+        match self {
+            Encoding::Grid => vec![GRID_CHANNELS, height, width],
+            Encoding::Features => vec![FEATURE_COUNT],
+            Encoding::Rays => vec![RAY_COUNT, RAY_VALUES],
+        }
+    }
+
+    pub fn encode(&self, view: &GameView) -> Observation {
+        let data = match self {
+            Encoding::Grid => grid(view),
+            Encoding::Features => features(view),
+            Encoding::Rays => rays(view),
+        };
+        Observation { shape: self.shape(view.dimensions()), data }
+    }
+}
+
+fn playable_size((width, height): (u16, u16)) -> (usize, usize) {
+    (
+        usize::from(width.saturating_sub(2 * BORDER_THICKNESS)),
+        usize::from(height.saturating_sub(2 * BORDER_THICKNESS)),
+    )
+}
+
+fn grid(view: &GameView) -> Vec<f32> {
+    let (width, height) = playable_size(view.dimensions());
+    let mut data = vec![0.0; GRID_CHANNELS * width * height];
+    let mut mark = |channel: usize, point: &Point| {
+        let (x, y) = (point.x.wrapping_sub(BORDER_THICKNESS), point.y.wrapping_sub(BORDER_THICKNESS));
+        if usize::from(x) < width && usize::from(y) < height {
+            data[(channel * height + usize::from(y)) * width + usize::from(x)] = 1.0;
+        }
+    };
+
+    let game = view.game();
+    for (index, player) in game.players().iter().enumerate() {
+        if !player.is_alive() && index != view.player() {
+            continue;
+        }
+        let body = player.snake().body();
+        for point in body {
+            mark(if index == view.player() { BODY } else { RIVAL }, point);
+        }
+        if index == view.player() {
+            if let Some(head) = body.back() {
+                mark(HEAD, head);
+            }
+        }
+    }
+    mark(FOOD, &view.food());
+    for point in view.obstacles().iter().flat_map(|obstacle| &obstacle.blocks) {
+        mark(OBSTACLE, point);
+    }
+    for point in game.pickups() {
+        mark(PICKUP, point);
+    }
+    if let Some(gate) = game.exit_gate() {
+        mark(GATE, gate);
+    }
+    data
+}
+
+fn features(view: &GameView) -> Vec<f32> {
+    let game = view.game();
+    let head = view.head();
+    let heading = view.direction();
+    let (width, height) = playable_size(view.dimensions());
+    let flag = |value: bool| if value { 1.0 } else { 0.0 };
+    let danger = |direction: Direction| flag(view.is_blocked(&view.step(&head, direction)));
+    let offset = |from: u16, to: u16, size: usize| (f32::from(to) - f32::from(from)) / size.max(1) as f32;
+
+    let progress = game.objective_progress();
+    let budget = game.objective().move_budget();
+    let gate = game.exit_gate();
+
+    let mut data = vec![
+        danger(heading),
+        danger(turn_left(heading)),
+        danger(turn_left(heading).opposite()),
+    ];
+    data.extend(Direction::ALL.map(|direction| flag(direction == heading)));
+    data.extend([
+        offset(head.x, view.food().x, width),
+        offset(head.y, view.food().y, height),
+        view.body().len() as f32 / (width * height).max(1) as f32,
+        (progress.current as f32 / progress.target.max(1) as f32).min(1.0),
+        (game.current_level() as f32 / game.max_levels().max(1) as f32).min(1.0),
+        match (progress.moves_left, budget) {
+            (Some(left), Some(budget)) => left as f32 / budget.max(1) as f32,
+            _ => 1.0,
+        },
+        flag(gate.is_some()),
+        gate.map_or(0.0, |gate| offset(head.x, gate.x, width)),
+        gate.map_or(0.0, |gate| offset(head.y, gate.y, height)),
+    ]);
+    data
+}
+
+fn rays(view: &GameView) -> Vec<f32> {
+    let (width, height) = view.dimensions();
+    // Wrap-around boards have no end, so rays give up after crossing the board
+    let limit = u32::from(width.max(height));
+    let head = view.head();
+    let body = view.body();
+
+    let mut data = Vec::with_capacity(RAY_COUNT * RAY_VALUES);
+    for steps in RAYS {
+        let (mut wall, mut snake, mut food) = (0.0, 0.0, 0.0);
+        let mut point = head;
+        for distance in 1..=limit {
+            point = steps.iter().fold(point, |point, direction| view.step(&point, *direction));
+            let closeness = 1.0 / distance as f32;
+            if view.is_wall(&point) {
+                wall = closeness;
+                break;
+            }
+            if snake == 0.0 && (body.contains(&point) || view.is_blocked_by_others(&point)) {
+                snake = closeness;
+            }
+            if food == 0.0 && point == view.food() {
+                food = closeness;
+            }
+        }
+        data.extend([wall, snake, food]);
+    }
+    data
+}
+
+fn turn_left(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Left,
+        Direction::Left => Direction::Down,
+        Direction::Down => Direction::Right,
+        Direction::Right => Direction::Up,
+    }
+}
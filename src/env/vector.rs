@@ -0,0 +1,70 @@
+// src/env/vector.rs
+use crate::{
+    entities::Direction,
+    utils::{Result, GameError},
+};
+use super::{EnvOptions, Observation, SnakeEnv, Step};
+
+// Several environments stepped together. A finished episode is reset right
+// away, so every step returns the first observation of the next episode; the
+// last one of the finished episode is kept in `StepInfo::final_observation`.
+pub struct VecEnv {
+    envs: Vec<SnakeEnv>,
+    next_seeds: Vec<u64>,
+}
+
+impl VecEnv {
+    pub fn new(options: EnvOptions, count: usize) -> Self {
+        Self {
+            envs: (0..count).map(|_| SnakeEnv::new(options.clone())).collect(),
+            next_seeds: vec![0; count],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.envs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.envs.is_empty()
+    }
+
+    pub fn envs(&self) -> &[SnakeEnv] {
+        &self.envs
+    }
+
+    // Environment `i` starts on `seed + i`; later episodes carry on from `seed + len`
+    pub fn reset(&mut self, seed: u64) -> Vec<Observation> {
+        let count = self.envs.len() as u64;
+        self.envs.iter_mut().zip(&mut self.next_seeds).enumerate()
+            .map(|(i, (env, next_seed))| {
+                let seed = seed + i as u64;
+                *next_seed = seed + count;
+                env.reset(seed)
+            })
+            .collect()
+    }
+
+    // One action per environment, in order
+    pub fn step(&mut self, actions: &[Direction]) -> Result<Vec<Step>> {
+        if actions.len() != self.envs.len() {
+            return Err(GameError::InvalidInput(format!(
+                "expected {} actions, got {}", self.envs.len(), actions.len()
+            )));
+        }
+
+        let count = self.envs.len() as u64;
+        self.envs.iter_mut().zip(&mut self.next_seeds).zip(actions)
+            .map(|((env, next_seed), action)| {
+                let (observation, reward, done, mut info) = env.step(*action)?;
+                if !done {
+                    return Ok((observation, reward, done, info));
+                }
+                info.final_observation = Some(observation);
+                let observation = env.reset(*next_seed);
+                *next_seed += count;
+                Ok((observation, reward, done, info))
+            })
+            .collect()
+    }
+}
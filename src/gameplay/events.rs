@@ -0,0 +1,14 @@
+// src/gameplay/events.rs
+
+// Things that happened during a tick, for systems that react to gameplay
+// without being part of the rules (achievements, stats, ...).
+// Timestamps are game time in milliseconds, summed from tick rates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameEvent {
+    Turned { at_ms: u64 },
+    FoodEaten { at_ms: u64, length: usize, score: u32 },
+    GateOpened { at_ms: u64 },
+    LevelComplete { level: u32, at_ms: u64 },
+    Died { level: u32, at_ms: u64 },
+    Victory { at_ms: u64 },
+}
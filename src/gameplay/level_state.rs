@@ -5,6 +5,7 @@ pub struct LevelState {
     pub current_level: u32,
     pub max_levels: u32,
     pub score_per_level: u32,
+    pub endless: bool,
 }
 
 impl LevelState {
@@ -13,15 +14,43 @@ impl LevelState {
             current_level: starting_level,
             max_levels,
             score_per_level,
+            endless: false,
         }
     }
 
+    // Levels never run out; each one asks for more food than the last
+    pub fn endless(starting_level: u32, score_per_level: u32) -> Self {
+        Self {
+            current_level: starting_level,
+            max_levels: u32::MAX,
+            score_per_level,
+            endless: true,
+        }
+    }
+
+    fn score_threshold(&self) -> u32 {
+        if self.endless {
+            // 5, 15, 30, 50, ... for a score_per_level of 5
+            self.score_per_level * self.current_level * (self.current_level + 1) / 2
+        } else {
+            self.score_per_level * self.current_level
+        }
+    }
+
+    pub fn target_reached(&self, score: u32) -> bool {
+        score >= self.score_threshold()
+    }
+
+    pub fn is_final_level(&self) -> bool {
+        !self.endless && self.current_level >= self.max_levels
+    }
+
     pub fn should_advance(&self, score: u32) -> bool {
-        let score_needed = self.score_per_level * self.current_level;
+        let score_needed = self.score_threshold();
         debug!("Checking level advance - Score: {}, Needed: {}, Current Level: {}, Max Levels: {}", 
             score, score_needed, self.current_level, self.max_levels);
         
-        if score >= score_needed && self.current_level < self.max_levels {
+        if self.target_reached(score) && !self.is_final_level() {
             debug!("Should advance to next level");
             true
         } else {
@@ -30,17 +59,21 @@ impl LevelState {
     }
 
     pub fn advance(&mut self) {
-        if self.current_level < self.max_levels {
+        if self.endless || self.current_level < self.max_levels {
             self.current_level += 1;
             debug!("Advanced to level {}", self.current_level);
         }
     }
 
     pub fn score_needed_for_next(&self) -> Option<u32> {
-        if self.current_level < self.max_levels {
-            Some(self.score_per_level * self.current_level)
-        } else {
+        if self.is_final_level() {
             None
+        } else {
+            Some(self.score_threshold())
         }
     }
-}
\ No newline at end of file
+
+    pub fn score_needed_for_current(&self) -> u32 {
+        self.score_threshold()
+    }
+}
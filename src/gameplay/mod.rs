@@ -1,14 +1,19 @@
 mod snake;
 mod pattern_generator;
 mod level_state;
+mod events;
+mod objectives;
 
 pub use snake::Snake;
-pub use pattern_generator::{ObstaclePattern, get_level_pattern};
+pub use pattern_generator::{ObstaclePattern, get_level_pattern, get_classic_pattern, get_endless_pattern};
 pub use level_state::LevelState;
+pub use events::GameEvent;
+pub use objectives::{Objective, ObjectiveProgress, campaign_objectives};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GameState {
     Playing,
+    Paused,
     LevelTransition,
     GameOver(GameEndReason),
 }
@@ -17,4 +22,52 @@ pub enum GameState {
 pub enum GameEndReason {
     Collision,
     Victory,
-}
\ No newline at end of file
+    OutOfMoves,
+    /// The snake filled every free square, leaving nowhere for food.
+    BoardFull,
+    /// Multiplayer: the player at this index won the match.
+    Winner(usize),
+    /// Multiplayer: every snake crashed, or the leaders tied.
+    Draw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    /// Fixed number of hand-tuned levels, ending in victory.
+    Classic,
+    /// Generated levels keep coming until the snake dies.
+    Endless,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WallMode {
+    /// Touching the border ends the game.
+    Solid,
+    /// Leaving the board re-enters from the opposite side.
+    Wrap,
+}
+
+// What a snake ran into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DeathCause {
+    Wall,
+    /// The snake's own body.
+    Itself,
+    Obstacle,
+    /// Another snake's body.
+    Snake,
+    /// Two heads landing on the same square.
+    HeadOn,
+}
+
+impl DeathCause {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeathCause::Wall => "wall",
+            DeathCause::Itself => "self",
+            DeathCause::Obstacle => "obstacle",
+            DeathCause::Snake => "snake",
+            DeathCause::HeadOn => "head-on",
+        }
+    }
+}
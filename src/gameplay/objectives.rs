@@ -0,0 +1,57 @@
+// src/gameplay/objectives.rs
+
+// What a level asks of the player before it counts as complete
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    /// Reach the level's cumulative score target from `LevelState`.
+    Score,
+    /// Grow the snake to the given length.
+    ReachLength(usize),
+    /// Eat the numbered pickups 1..=n, in that order.
+    CollectInOrder(u32),
+    /// Stay alive for the given number of seconds of game time.
+    Survive { seconds: u32 },
+    /// Eat every pickup before the move budget runs out.
+    CollectAll { count: u32, moves: u32 },
+}
+
+impl Objective {
+    pub fn pickup_count(&self) -> u32 {
+        match self {
+            Objective::CollectInOrder(count) => *count,
+            Objective::CollectAll { count, .. } => *count,
+            _ => 0,
+        }
+    }
+
+    pub fn move_budget(&self) -> Option<u32> {
+        match self {
+            Objective::CollectAll { moves, .. } => Some(*moves),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectiveProgress {
+    pub current: u32,
+    pub target: u32,
+    pub moves_left: Option<u32>,
+}
+
+impl ObjectiveProgress {
+    pub fn is_complete(&self) -> bool {
+        self.current >= self.target
+    }
+}
+
+// A short campaign that walks through every kind of objective
+pub fn campaign_objectives() -> Vec<Objective> {
+    vec![
+        Objective::Score,
+        Objective::ReachLength(12),
+        Objective::CollectInOrder(5),
+        Objective::Survive { seconds: 45 },
+        Objective::CollectAll { count: 6, moves: 400 },
+    ]
+}
@@ -1,4 +1,7 @@
 // src/gameplay/pattern_generator.rs
+use std::collections::HashSet;
+use rand::Rng;
+use crate::config::{Config, BASE_OBSTACLES, OBSTACLES_PER_LEVEL, ENDLESS_MAX_OBSTACLE_PERCENT};
 
 pub struct ObstaclePattern {
     pub positions: Vec<(u16, u16)>,
@@ -7,7 +10,25 @@ pub struct ObstaclePattern {
 
 pub fn get_level_pattern(level: u32, width: u16, height: u16) -> ObstaclePattern {
     let grid_size = (level + 1) as u16;       // 2x2 for level 1, 3x3 for level 2, 4x4 for level 3, ...
+    grid_pattern(grid_size, width, height)
+}
+
+// The classic grid, thinned out or packed tighter by the preset's obstacle
+// density. The default density gives exactly `get_level_pattern`.
+pub fn get_classic_pattern(level: u32, config: &Config) -> ObstaclePattern {
+    let obstacles = |base: u32, per_level: u32| f64::from(base + per_level * level.saturating_sub(1));
+    let density = obstacles(config.base_obstacles, config.obstacles_per_level) /
+        obstacles(BASE_OBSTACLES, OBSTACLES_PER_LEVEL).max(1.0);
+    let grid_size = (f64::from(level + 1) * density.sqrt()).round() as u16;
+
+    // Denser grids keep at least a one-square gap between the 2x2 blocks
+    let margin = 4;
+    let fits = |size: u16| (size.saturating_sub(2 * margin) / 3).saturating_sub(1);
+    let grid_size = grid_size.min(fits(config.width).min(fits(config.height)).max(level as u16 + 1));
+    grid_pattern(grid_size, config.width, config.height)
+}
 
+fn grid_pattern(grid_size: u16, width: u16, height: u16) -> ObstaclePattern {
     // Calculate margins to leave space around edges
     let margin = 4;
     let playable_width = width - 2 * margin;
@@ -35,4 +56,60 @@ pub fn get_level_pattern(level: u32, width: u16, height: u16) -> ObstaclePattern
         positions,
         sizes,
     }
-}
\ No newline at end of file
+}
+
+// Scattered layout for endless mode: the obstacle count keeps growing with the
+// level until it hits the density cap, so later levels stay crowded but playable.
+pub fn get_endless_pattern(level: u32, config: &Config, rng: &mut impl Rng) -> ObstaclePattern {
+    let mut positions = Vec::new();
+    let mut sizes = Vec::new();
+
+    // A settings file can leave the list empty, which means no obstacles
+    if config.obstacle_sizes.is_empty() {
+        return ObstaclePattern { positions, sizes };
+    }
+
+    let margin = 4;
+    let playable_width = config.width.saturating_sub(2 * margin);
+    let playable_height = config.height.saturating_sub(2 * margin);
+
+    let wanted = config.base_obstacles + config.obstacles_per_level * level.saturating_sub(1);
+    let max_cells = u32::from(playable_width) * u32::from(playable_height) * ENDLESS_MAX_OBSTACLE_PERCENT / 100;
+
+    let mut occupied: HashSet<(u16, u16)> = HashSet::new();
+    let mut covered = 0;
+    let mut attempts = 0;
+
+    while (positions.len() as u32) < wanted && attempts < wanted * 20 {
+        attempts += 1;
+
+        let w = config.obstacle_sizes[rng.gen_range(0..config.obstacle_sizes.len())];
+        let h = config.obstacle_sizes[rng.gen_range(0..config.obstacle_sizes.len())];
+        if playable_width <= w || playable_height <= h || covered + u32::from(w * h) > max_cells {
+            break;
+        }
+
+        let x = margin + rng.gen_range(0..playable_width - w);
+        let y = margin + rng.gen_range(0..playable_height - h);
+
+        // Keep a one-cell gap around every obstacle so no pocket gets sealed off
+        let blocked = (x - 1..=x + w).any(|bx| (y - 1..=y + h).any(|by| occupied.contains(&(bx, by))));
+        if blocked {
+            continue;
+        }
+
+        for bx in x..x + w {
+            for by in y..y + h {
+                occupied.insert((bx, by));
+            }
+        }
+        covered += u32::from(w * h);
+        positions.push((x, y));
+        sizes.push((w, h));
+    }
+
+    ObstaclePattern {
+        positions,
+        sizes,
+    }
+}
@@ -8,16 +8,21 @@ pub struct Snake {
 
 impl Snake {
     pub fn new(start_x: u16, start_y: u16) -> Self {
-        let mut body = VecDeque::new();
+        Self::with_direction(start_x, start_y, Direction::Right)
+    }
+
+    // Head at the start point, with the body trailing behind it
+    pub fn with_direction(start_x: u16, start_y: u16, direction: Direction) -> Self {
+        let head = Point::new(start_x, start_y);
+        let behind = direction.opposite();
+        let neck = head.translate(&behind);
         
         // Initialize with 3 segments
-        body.push_back(Point::new(start_x - 2, start_y));
-        body.push_back(Point::new(start_x - 1, start_y));
-        body.push_back(Point::new(start_x, start_y));
+        let body = VecDeque::from([neck.translate(&behind), neck, head]);
 
         Self {
             body,
-            direction: Direction::Right,
+            direction,
         }
     }
 
@@ -3,6 +3,8 @@ pub mod gameplay;
 pub mod ui;
 pub mod entities;
 pub mod utils;
+pub mod bot;
+pub mod env;
 
 pub mod config;
 pub use config::*;  // Export everything from config
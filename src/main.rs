@@ -1,10 +1,14 @@
 use snake_game::{
-    ui::{Renderer, InputHandler},
-    core::GameState,
-    config::Config,
+    ui::{
+        Renderer, Hint, CrosstermBackend, InputEvent, InputThread, TimedEvent, Theme, Animation, Action, Context, KeyBindings,
+        render_text,
+    },
+    core::{GameState, HighScores, HIGH_SCORE_FILE, AchievementTracker, ACHIEVEMENT_FILE},
+    config::{Config, Difficulty, Settings, SETTINGS_FILE, WIDTH, HEIGHT, MAX_PLAYERS},
     utils::Result,
     entities::Direction,
-    gameplay::GameState as GameStateEnum,
+    bot::{Bot, AStarBot, ExternalBot, GameView, bot_by_name, BOT_NAMES, DEFAULT_MOVE_TIMEOUT},
+    gameplay::{GameState as GameStateEnum, GameMode, campaign_objectives},
 };
 use std::{
     time::{Duration, Instant},
@@ -44,96 +48,461 @@ fn main() -> CrosstermResult<()> {
     Ok(())
 }
 
+struct Options {
+    mode: GameMode,
+    difficulty: Option<Difficulty>,
+    campaign: bool,
+    exit_gate: bool,
+    theme: Option<String>,
+    keys: Option<String>,
+    seed: Option<u64>,
+    side_panel: bool,
+    hint: bool,
+    square_cells: bool,
+    players: usize,
+    bot: Option<Box<dyn Bot>>,
+    bot_command: Option<String>,
+    bot_timeout: Duration,
+}
+
+fn parse_args() -> Options {
+    let mut options = Options {
+        mode: GameMode::Classic,
+        difficulty: None,
+        campaign: false,
+        exit_gate: false,
+        theme: None,
+        keys: None,
+        seed: None,
+        side_panel: false,
+        hint: false,
+        square_cells: false,
+        players: 1,
+        bot: None,
+        bot_command: None,
+        bot_timeout: DEFAULT_MOVE_TIMEOUT,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--endless" => options.mode = GameMode::Endless,
+            "--campaign" => options.campaign = true,
+            "--exit-gate" => options.exit_gate = true,
+            "--side-panel" => options.side_panel = true,
+            "--hint" => options.hint = true,
+            "--square" => options.square_cells = true,
+            "--theme" => options.theme = args.next(),
+            "--keys" => options.keys = args.next(),
+            "--seed" => match args.next().and_then(|s| s.parse().ok()) {
+                Some(seed) => options.seed = Some(seed),
+                None => log::warn!("--seed expects a number"),
+            },
+            "--players" => match args.next().and_then(|s| s.parse().ok()) {
+                Some(players @ 2..=MAX_PLAYERS) => options.players = players,
+                _ => log::warn!("--players expects a number from 2 to {}", MAX_PLAYERS),
+            },
+            "--bot" => match args.next().as_deref().and_then(bot_by_name) {
+                Some(bot) => options.bot = Some(bot),
+                None => log::warn!("--bot expects one of: {}", BOT_NAMES.join(", ")),
+            },
+            "--bot-cmd" => options.bot_command = args.next(),
+            "--bot-timeout" => match args.next().and_then(|s| s.parse().ok()) {
+                Some(ms) => options.bot_timeout = Duration::from_millis(ms),
+                None => log::warn!("--bot-timeout expects a number of milliseconds"),
+            },
+            "--preset" => match args.next().as_deref().and_then(Difficulty::from_name) {
+                Some(difficulty) => options.difficulty = Some(difficulty),
+                None => log::warn!("--preset expects one of: easy, normal, hard, insane"),
+            },
+            other => log::warn!("Ignoring unknown argument: {}", other),
+        }
+    }
+
+    // Started last, once the timeout is known
+    if let Some(command) = &options.bot_command {
+        if let Some(bot) = &options.bot {
+            log::warn!("--bot-cmd replaces --bot {}", bot.name());
+        }
+        match ExternalBot::spawn(command, options.bot_timeout) {
+            Ok(bot) => options.bot = Some(Box::new(bot)),
+            Err(e) => log::warn!("{}", e),
+        }
+    }
+
+    options
+}
+
+// Returns None if the player quits from the menu
+fn choose_difficulty(
+    renderer: &mut Renderer,
+    input: &InputThread,
+    bindings: &KeyBindings,
+    high_scores: &HighScores,
+    achievements: &AchievementTracker,
+    score_key: impl Fn(Difficulty) -> String,
+) -> Result<Option<Difficulty>> {
+    let mut selected = 1; // Normal
+    let mut needs_redraw = true;
+    let mut showing_achievements = false;
+
+    loop {
+        if needs_redraw {
+            if showing_achievements {
+                renderer.render_achievements(achievements)?;
+            } else {
+                let options: Vec<String> = Difficulty::ALL.iter()
+                    .map(|d| match high_scores.best(&score_key(*d)) {
+                        Some(best) => format!("{:<6}  best {}", d.name(), best),
+                        None => format!("{:<6}  best -", d.name()),
+                    })
+                    .collect();
+                renderer.render_menu("Choose difficulty", &options, selected)?;
+            }
+            needs_redraw = false;
+        }
+
+        // Waiting for input doubles as the menu's frame delay
+        for TimedEvent { event, .. } in input.events_until(Instant::now() + Duration::from_millis(16))? {
+            if event.is_interrupt() {
+                return Ok(None);
+            }
+            if let InputEvent::Resize(columns, rows) = event {
+                renderer.handle_terminal_resize(columns, rows);
+                needs_redraw = true;
+            }
+            let Some(key) = event.pressed_key() else {
+                continue;
+            };
+
+            // Any key leaves the achievements list
+            if showing_achievements {
+                showing_achievements = false;
+                needs_redraw = true;
+                continue;
+            }
+
+            // Number keys pick a preset directly
+            if let KeyCode::Char(c @ '1'..='4') = key {
+                return Ok(Some(Difficulty::ALL[c as usize - '1' as usize]));
+            }
+
+            match bindings.action(key, Context::Menu) {
+                Some(Action::Achievements) => {
+                    showing_achievements = true;
+                    needs_redraw = true;
+                }
+                Some(Action::MoveUp) => {
+                    selected = selected.saturating_sub(1);
+                    needs_redraw = true;
+                }
+                Some(Action::MoveDown) => {
+                    selected = (selected + 1).min(Difficulty::ALL.len() - 1);
+                    needs_redraw = true;
+                }
+                Some(Action::Confirm) => return Ok(Some(Difficulty::ALL[selected])),
+                Some(Action::Quit) => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn load_settings(options: &Options) -> (Theme, KeyBindings) {
+    let mut settings = Settings::load(SETTINGS_FILE).unwrap_or_else(|e| {
+        log::warn!("Could not load settings: {}", e);
+        Settings::default()
+    });
+    if options.theme.is_some() {
+        settings.theme.name = options.theme.clone();
+    }
+    if options.keys.is_some() {
+        settings.keys.preset = options.keys.clone();
+    }
+
+    let theme = Theme::from_settings(&settings.theme).unwrap_or_else(|e| {
+        log::warn!("Could not load theme: {}", e);
+        Theme::default()
+    });
+    let bindings = KeyBindings::from_settings(&settings.keys).unwrap_or_else(|e| {
+        log::warn!("Could not load key bindings: {}", e);
+        KeyBindings::default()
+    });
+    (theme, bindings)
+}
+
+fn tick_interval(game_state: &GameState) -> Duration {
+    Duration::from_millis(game_state.get_tick_rate())
+}
+
+// The A* bot's route for player 1, or a warning when it can't find a safe one
+fn hint_for(game_state: &GameState, hint_bot: &mut AStarBot) -> Hint {
+    hint_bot.next_direction(&GameView::new(game_state));
+    if hint_bot.found_safe_path() {
+        Hint::Path(hint_bot.path().to_vec())
+    } else {
+        Hint::NoSafePath
+    }
+}
+
+// The high score table for this preset, mode and campaign
+fn score_key(options: &Options, difficulty: Difficulty) -> String {
+    if options.campaign {
+        HighScores::campaign_key(difficulty, options.mode)
+    } else {
+        HighScores::key(difficulty, options.mode)
+    }
+}
+
+fn alive_players(game_state: &GameState) -> usize {
+    game_state.players().iter().filter(|player| player.is_alive()).count()
+}
+
+// Holds the game while the board can't be shown in full, whichever way it got to playing
+fn hold_for_size(game_state: &mut GameState, renderer: &Renderer, paused_for_size: &mut bool) {
+    if !renderer.fits_terminal() && game_state.game_state() == GameStateEnum::Playing {
+        game_state.pause();
+        *paused_for_size = true;
+    }
+}
+
+// Runs every tick due at or before `until`, so input lands between the right ticks
+fn run_ticks(
+    game_state: &mut GameState,
+    renderer: &mut Renderer,
+    next_tick: &mut Instant,
+    until: Instant,
+    bot: &mut Option<Box<dyn Bot>>,
+) -> Result<()> {
+    while game_state.game_state() == GameStateEnum::Playing && !renderer.holds_play() && *next_tick <= until {
+        // The bot decides with the board exactly as the tick will find it
+        if let Some(bot) = bot {
+            let direction = bot.next_direction(&GameView::new(game_state));
+            game_state.change_direction(direction);
+        }
+
+        let alive = alive_players(game_state);
+        game_state.update()?;
+        *next_tick += tick_interval(game_state);
+
+        if game_state.game_state() == GameStateEnum::LevelTransition {
+            renderer.play(Animation::Wipe);
+        }
+        if alive_players(game_state) < alive {
+            renderer.play(Animation::DeathFlash);
+        }
+    }
+    Ok(())
+}
+
 fn run_game() -> Result<()> {
-    // Load configuration
-    let config = Config::new();
-    
-    // Initialize game components
-    let mut game_state = GameState::new();
-    let mut renderer = Renderer::new(config.width, config.height);
-    let input_handler = InputHandler::new();
+    let mut options = parse_args();
+    let mut bot = options.bot.take();
+    let mut renderer = Renderer::with_backend(CrosstermBackend::new(), WIDTH, HEIGHT);
+    let (theme, bindings) = load_settings(&options);
+    renderer.set_theme(theme);
+    renderer.set_bindings(bindings.clone());
+    renderer.set_side_panel(options.side_panel);
+    renderer.set_square_cells(options.square_cells);
 
     // Initialize terminal
     renderer.init()?;
+    let input = InputThread::spawn();
 
     // Give the terminal a moment to initialize
     thread::sleep(Duration::from_millis(100));
 
+    let mut high_scores = HighScores::load(HIGH_SCORE_FILE).unwrap_or_else(|e| {
+        log::warn!("Could not load high scores: {}", e);
+        HighScores::new()
+    });
+
+    let mut achievements = AchievementTracker::load(ACHIEVEMENT_FILE).unwrap_or_else(|e| {
+        log::warn!("Could not load achievements: {}", e);
+        AchievementTracker::new()
+    });
+
+    let difficulty = match options.difficulty {
+        Some(difficulty) => difficulty,
+        None => match choose_difficulty(&mut renderer, &input, &bindings, &high_scores, &achievements, |d| score_key(&options, d))? {
+            Some(difficulty) => difficulty,
+            None => return renderer.cleanup(),
+        },
+    };
+
+    // Load configuration
+    let mut config = Config::from_difficulty(difficulty);
+    config.mode = options.mode;
+    config.exit_gate = options.exit_gate;
+    config.seed = options.seed;
+    config.players = options.players;
+    if options.campaign {
+        config.level_objectives = campaign_objectives();
+    }
+    let (columns, mut rows) = renderer.terminal_size();
+    if options.square_cells {
+        // Each terminal row holds two board rows; the status bar still needs a whole one
+        rows = rows.saturating_sub(1) * 2 + 1;
+    }
+    config.fit_to_terminal(columns, rows);
+    renderer.resize(config.width, config.height);
+
+    // Initialize game components
+    let mut game_state = GameState::with_config(config);
+    let score_key = score_key(&options, difficulty);
+    if options.players == 1 {
+        renderer.set_best_score(high_scores.best(&score_key));
+    }
+
     // Initial render
+    renderer.play(Animation::Countdown);
     renderer.render(&game_state)?;
 
     // Game loop timing
     let frame_rate = Duration::from_millis(50); // ~30 FPS
-    let mut last_tick = Instant::now();
+    let mut next_tick = Instant::now() + tick_interval(&game_state);
     let mut last_render = Instant::now();
+    let mut paused_for_size = false;
+    let mut bot_warning = None;
+    let mut show_hint = options.hint;
+    let mut hint_bot = AStarBot::new();
 
     while !matches!(game_state.game_state(), GameStateEnum::GameOver(_)) {
-        // Handle input
-        if let Ok(Some(key)) = input_handler.get_input() {
-            match game_state.game_state() {
-                GameStateEnum::Playing => {
-                    match key {
-                        KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
-                            game_state.change_direction(Direction::Up);
-                        }
-                        KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => {
-                            game_state.change_direction(Direction::Down);
-                        }
-                        KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A') => {
-                            game_state.change_direction(Direction::Left);
-                        }
-                        KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D') => {
-                            game_state.change_direction(Direction::Right);
-                        }
-                        KeyCode::Char('q') | KeyCode::Char('Q') => break,
-                        _ => {}
+        hold_for_size(&mut game_state, &renderer, &mut paused_for_size);
+
+        // The clock stands still while paused, between levels and during the countdown
+        if game_state.game_state() != GameStateEnum::Playing || renderer.holds_play() {
+            next_tick = Instant::now() + tick_interval(&game_state);
+        }
+
+        // Wait for the next tick or frame, waking early for input
+        let deadline = next_tick.min(last_render + frame_rate);
+        let mut quit = false;
+        for TimedEvent { event, at } in input.events_until(deadline)? {
+            // Ticks that were due before the event happened come first
+            run_ticks(&mut game_state, &mut renderer, &mut next_tick, at, &mut bot)?;
+
+            match event {
+                InputEvent::Resize(columns, rows) => {
+                    renderer.handle_terminal_resize(columns, rows);
+
+                    hold_for_size(&mut game_state, &renderer, &mut paused_for_size);
+                    if renderer.fits_terminal() && paused_for_size {
+                        game_state.resume();
+                        paused_for_size = false;
+                        next_tick = Instant::now() + tick_interval(&game_state);
                     }
+                    renderer.render(&game_state)?;
                 }
-                GameStateEnum::LevelTransition => {
-                    match key {
-                        KeyCode::Char(' ') => game_state.start_next_level(),
-                        KeyCode::Char('q') | KeyCode::Char('Q') => break,
-                        _ => {}
-                    }
+                // Nobody is watching; the player resumes with the pause key
+                InputEvent::FocusLost => game_state.pause(),
+                _ if event.is_interrupt() => quit = true,
+                _ => {}
+            }
+
+            // In a match each player has their own keys instead of the move actions
+            let multiplayer = game_state.players().len() > 1;
+            let player_move = event.pressed_key().filter(|_| multiplayer).and_then(|key| bindings.player_move(key));
+            if let Some((player, direction)) = player_move {
+                if game_state.game_state() == GameStateEnum::Playing && (bot.is_none() || player != 0) {
+                    game_state.queue_turn_for(player, direction);
                 }
-                GameStateEnum::GameOver(_) => {
-                    if matches!(key, KeyCode::Char('q') | KeyCode::Char('Q')) {
-                        break;
+                continue;
+            }
+
+            let action = event.pressed_key().and_then(|key| bindings.action(key, Context::Game));
+            match (game_state.game_state(), action) {
+                (_, Some(Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight)) if multiplayer => {}
+                (_, Some(Action::Quit)) => quit = true,
+                // The bot has the controls; the player can still pause or quit
+                (_, Some(Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight)) if bot.is_some() => {}
+                (GameStateEnum::Playing, Some(Action::MoveUp)) => game_state.queue_turn(Direction::Up),
+                (GameStateEnum::Playing, Some(Action::MoveDown)) => game_state.queue_turn(Direction::Down),
+                (GameStateEnum::Playing, Some(Action::MoveLeft)) => game_state.queue_turn(Direction::Left),
+                (GameStateEnum::Playing, Some(Action::MoveRight)) => game_state.queue_turn(Direction::Right),
+                (_, Some(Action::Hint)) => {
+                    show_hint = !show_hint;
+                    if !show_hint {
+                        renderer.set_hint(Hint::Off);
                     }
                 }
+                (GameStateEnum::Playing, Some(Action::Pause)) => game_state.pause(),
+                // Neither resuming nor the next level can start until the board fits
+                (GameStateEnum::Paused, Some(Action::Pause)) if !paused_for_size && renderer.fits_terminal() => {
+                    game_state.resume();
+                    next_tick = Instant::now() + tick_interval(&game_state);
+                }
+                (GameStateEnum::LevelTransition, Some(Action::Confirm)) if renderer.fits_terminal() => {
+                    game_state.start_next_level();
+                    renderer.play(Animation::Wipe);
+                    renderer.play(Animation::Countdown);
+                }
+                _ => {}
             }
         }
+        if quit {
+            break;
+        }
 
-        match game_state.game_state() {
-            GameStateEnum::Playing => {
-                // Get current tick rate based on speed level
-                let current_tick_rate = Duration::from_millis(game_state.get_tick_rate());
+        run_ticks(&mut game_state, &mut renderer, &mut next_tick, Instant::now(), &mut bot)?;
 
-                // Update game state at current speed
-                if last_tick.elapsed() >= current_tick_rate {
-                    game_state.update()?;
-                    last_tick = Instant::now();
-                }
+        // Let the player know when the bot can't play the way it's meant to
+        let warning = bot.as_ref().and_then(|bot| bot.warning());
+        if warning != bot_warning {
+            if let Some(text) = &warning {
+                renderer.show_toast(text.clone());
+            }
+            bot_warning = warning;
+        }
+
+        // Feed this frame's events to the achievement tracker; bots don't earn any
+        let mut unlocked_any = false;
+        for event in game_state.drain_events().into_iter().filter(|_| bot.is_none()) {
+            for achievement in achievements.process(&event) {
+                renderer.show_toast(format!("Achievement unlocked: {}", achievement.name));
+                unlocked_any = true;
             }
-            _ => {
-                // For transitions and game over, just update without timing
-                game_state.update()?;
+        }
+        if unlocked_any {
+            if let Err(e) = achievements.save(ACHIEVEMENT_FILE) {
+                log::warn!("Could not save achievements: {}", e);
             }
         }
 
         // Render at frame rate
         if last_render.elapsed() >= frame_rate {
+            if show_hint {
+                renderer.set_hint(hint_for(&game_state, &mut hint_bot));
+            }
             renderer.render(&game_state)?;
             last_render = Instant::now();
         }
+    }
 
-        // Small sleep to prevent CPU hogging
-        thread::sleep(Duration::from_millis(16));
+    if matches!(game_state.game_state(), GameStateEnum::GameOver(_)) {
+        // Match and bot scores aren't comparable with solo games
+        if game_state.players().len() == 1 && bot.is_none() {
+            high_scores.record(&score_key, game_state.score());
+            renderer.set_best_score(high_scores.best(&score_key));
+            if let Err(e) = high_scores.save(HIGH_SCORE_FILE) {
+                log::warn!("Could not save high scores: {}", e);
+            }
+        }
+        log::info!("Final frame:\n{}", render_text(&game_state));
+    }
+
+    // Quitting skips whatever is still animating
+    if !matches!(game_state.game_state(), GameStateEnum::GameOver(_)) {
+        renderer.stop_animations();
     }
 
-    // Show final state
+    // Show final state, letting the death flash play out
     renderer.render(&game_state)?;
-    
+    while renderer.is_animating() {
+        thread::sleep(Duration::from_millis(16));
+        renderer.render(&game_state)?;
+    }
+
     // Wait for a moment before exit
     thread::sleep(Duration::from_secs(1));
 
@@ -0,0 +1,92 @@
+// src/ui/animation.rs
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+const COUNTDOWN_STEP: Duration = Duration::from_millis(500);
+const COUNTDOWN_LABELS: [&str; 4] = ["3", "2", "1", "GO!"];
+pub const FLASH_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Animation {
+    /// A band sweeping across the board between levels
+    Wipe,
+    /// "3-2-1-GO" before the snake starts moving
+    Countdown,
+    /// The snake blinking after a crash
+    DeathFlash,
+}
+
+impl Animation {
+    pub fn duration(&self) -> Duration {
+        match self {
+            Animation::Wipe => Duration::from_millis(400),
+            Animation::Countdown => COUNTDOWN_STEP * COUNTDOWN_LABELS.len() as u32,
+            Animation::DeathFlash => FLASH_INTERVAL * 6,
+        }
+    }
+
+    // Whether the game should wait for this animation before moving the snake
+    pub fn holds_play(&self) -> bool {
+        matches!(self, Animation::Wipe | Animation::Countdown)
+    }
+}
+
+pub fn countdown_label(elapsed: Duration) -> &'static str {
+    let step = (elapsed.as_millis() / COUNTDOWN_STEP.as_millis()) as usize;
+    COUNTDOWN_LABELS[step.min(COUNTDOWN_LABELS.len() - 1)]
+}
+
+// Plays animations one after another. Nothing here sleeps: callers ask what is
+// showing at a given instant and draw it, so the input loop keeps running.
+#[derive(Debug, Default)]
+pub struct Animator {
+    queue: VecDeque<Animation>,
+    started: Option<Instant>,
+}
+
+impl Animator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Queues an animation to start once the ones before it have finished
+    pub fn play(&mut self, animation: Animation, now: Instant) {
+        if self.queue.is_empty() {
+            self.started = Some(now);
+        }
+        self.queue.push_back(animation);
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.started = None;
+    }
+
+    // The animation showing at `now` and how far into it we are
+    pub fn current(&mut self, now: Instant) -> Option<(Animation, Duration)> {
+        while let Some(&animation) = self.queue.front() {
+            let started = *self.started.get_or_insert(now);
+            let elapsed = now.saturating_duration_since(started);
+            if elapsed < animation.duration() {
+                return Some((animation, elapsed));
+            }
+
+            // The next one picks up where this one ended
+            self.queue.pop_front();
+            self.started = Some(started + animation.duration());
+        }
+
+        self.started = None;
+        None
+    }
+
+    pub fn holds_play(&mut self, now: Instant) -> bool {
+        self.current(now).is_some_and(|(animation, _)| animation.holds_play())
+    }
+
+    pub fn is_idle(&mut self, now: Instant) -> bool {
+        self.current(now).is_none()
+    }
+}
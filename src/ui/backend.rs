@@ -0,0 +1,141 @@
+// src/ui/backend.rs
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{EnableFocusChange, DisableFocusChange},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen, enable_raw_mode, disable_raw_mode},
+};
+use std::io::{stdout, Stdout};
+use crate::{
+    utils::Result,
+    ui::{Cell, FrameBuffer},
+};
+
+// Where finished frames end up. The renderer composes into a `FrameBuffer`
+// and hands it to the backend, which only needs to apply the changed cells.
+pub trait RenderBackend {
+    // Size of the drawing area in cells, if the backend knows it
+    fn size(&self) -> Option<(u16, u16)>;
+    fn init(&mut self) -> Result<()>;
+    fn cleanup(&mut self) -> Result<()>;
+    fn draw(&mut self, buffer: &mut FrameBuffer) -> Result<()>;
+}
+
+pub struct CrosstermBackend {
+    stdout: Stdout,
+}
+
+impl CrosstermBackend {
+    pub fn new() -> Self {
+        Self { stdout: stdout() }
+    }
+}
+
+impl Default for CrosstermBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderBackend for CrosstermBackend {
+    fn size(&self) -> Option<(u16, u16)> {
+        terminal::size().ok()
+    }
+
+    fn init(&mut self) -> Result<()> {
+        enable_raw_mode()?;
+        execute!(self.stdout, EnterAlternateScreen, Hide, EnableFocusChange)?;
+        Ok(())
+    }
+
+    fn cleanup(&mut self) -> Result<()> {
+        execute!(self.stdout, DisableFocusChange, Show, LeaveAlternateScreen)?;
+        disable_raw_mode()?;
+        Ok(())
+    }
+
+    fn draw(&mut self, buffer: &mut FrameBuffer) -> Result<()> {
+        buffer.present(&mut self.stdout)
+    }
+}
+
+// Keeps the "screen" as a grid of cells so frames can be inspected without a TTY
+pub struct MemoryBackend {
+    width: u16,
+    height: u16,
+    cells: Vec<Cell>,
+    frames: usize,
+}
+
+impl MemoryBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::default(); usize::from(width) * usize::from(height)],
+            frames: 0,
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    // Number of frames drawn so far
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn cell(&self, x: u16, y: u16) -> Option<Cell> {
+        (x < self.width && y < self.height)
+            .then(|| self.cells[usize::from(y) * usize::from(self.width) + usize::from(x)])
+    }
+
+    pub fn line(&self, y: u16) -> String {
+        (0..self.width).filter_map(|x| self.cell(x, y)).map(|cell| cell.ch).collect()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        (0..self.height).map(|y| self.line(y)).collect()
+    }
+
+    // Position of the first cell showing `ch`, scanning row by row
+    pub fn find(&self, ch: char) -> Option<(u16, u16)> {
+        self.cells.iter().position(|cell| cell.ch == ch).map(|i| {
+            let width = usize::from(self.width);
+            ((i % width) as u16, (i / width) as u16)
+        })
+    }
+}
+
+impl RenderBackend for MemoryBackend {
+    fn size(&self) -> Option<(u16, u16)> {
+        Some((self.width, self.height))
+    }
+
+    fn init(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cleanup(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn draw(&mut self, buffer: &mut FrameBuffer) -> Result<()> {
+        // Follow the renderer when it resizes its buffer, like a terminal would
+        if (buffer.width(), buffer.height()) != (self.width, self.height) {
+            *self = Self { frames: self.frames, ..Self::new(buffer.width(), buffer.height()) };
+        }
+
+        for (x, y, cell) in buffer.changes() {
+            self.cells[usize::from(y) * usize::from(self.width) + usize::from(x)] = cell;
+        }
+        buffer.commit();
+        self.frames += 1;
+        Ok(())
+    }
+}
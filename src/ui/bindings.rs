@@ -0,0 +1,298 @@
+// src/ui/bindings.rs
+use std::collections::BTreeMap;
+use crossterm::event::KeyCode;
+use crate::{
+    utils::{Result, GameError},
+    entities::Direction,
+    config::{KeySettings, MAX_PLAYERS},
+};
+
+pub const BINDING_PRESETS: [&str; 3] = ["default", "vim", "azerty"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Pause,
+    Confirm,
+    Quit,
+    Achievements,
+    Hint,
+}
+
+// Where an action is looked up. Keys only clash if their actions share a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    Menu,
+    Game,
+}
+
+impl Action {
+    pub const ALL: [Action; 9] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Pause,
+        Action::Confirm,
+        Action::Quit,
+        Action::Achievements,
+        Action::Hint,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::MoveUp => "move_up",
+            Action::MoveDown => "move_down",
+            Action::MoveLeft => "move_left",
+            Action::MoveRight => "move_right",
+            Action::Pause => "pause",
+            Action::Confirm => "confirm",
+            Action::Quit => "quit",
+            Action::Achievements => "achievements",
+            Action::Hint => "hint",
+        }
+    }
+
+    // Move actions are the ones a match hands over to each player's keys
+    pub fn is_move(&self) -> bool {
+        matches!(self, Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight)
+    }
+
+    pub fn contexts(&self) -> &'static [Context] {
+        match self {
+            // Up and down also move the menu selection
+            Action::MoveUp | Action::MoveDown | Action::Confirm | Action::Quit => &[Context::Menu, Context::Game],
+            Action::MoveLeft | Action::MoveRight | Action::Pause | Action::Hint => &[Context::Game],
+            Action::Achievements => &[Context::Menu],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    keys: BTreeMap<Action, Vec<KeyCode>>,
+    players: Vec<[KeyCode; 4]>,  // Up, down, left and right for each player in a match
+}
+
+// Player order in multiplayer key sets
+const PLAYER_DIRECTIONS: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+impl KeyBindings {
+    pub fn by_name(name: &str) -> Option<Self> {
+        let (up, down, left, right, quit, third) = match name {
+            "default" => ('w', 's', 'a', 'd', Some('q'), ['i', 'k', 'j', 'l']),
+            "vim" => ('k', 'j', 'h', 'l', Some('q'), ['w', 's', 'a', 'd']),
+            // ZQSD sits where WASD is; 'q' moves left, so quitting is Escape only
+            "azerty" => ('z', 's', 'q', 'd', None, ['i', 'k', 'j', 'l']),
+            _ => return None,
+        };
+
+        // In a match the letters and arrows split between the first two players,
+        // with a third letter cluster and the number pad for players 3 and 4
+        let players = vec![
+            [up, down, left, right].map(KeyCode::Char),
+            [KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right],
+            third.map(KeyCode::Char),
+            ['8', '5', '4', '6'].map(KeyCode::Char),
+        ];
+
+        let mut keys = BTreeMap::new();
+        keys.insert(Action::MoveUp, vec![KeyCode::Up, KeyCode::Char(up)]);
+        keys.insert(Action::MoveDown, vec![KeyCode::Down, KeyCode::Char(down)]);
+        keys.insert(Action::MoveLeft, vec![KeyCode::Left, KeyCode::Char(left)]);
+        keys.insert(Action::MoveRight, vec![KeyCode::Right, KeyCode::Char(right)]);
+        keys.insert(Action::Pause, vec![KeyCode::Char('p')]);
+        keys.insert(Action::Confirm, vec![KeyCode::Char(' '), KeyCode::Enter]);
+        keys.insert(Action::Quit, quit.map(KeyCode::Char).into_iter().chain([KeyCode::Esc]).collect());
+        keys.insert(Action::Achievements, vec![KeyCode::Char('a')]);
+        keys.insert(Action::Hint, vec![KeyCode::Char('?')]);
+
+        Some(Self { keys, players })
+    }
+
+    pub fn from_settings(settings: &KeySettings) -> Result<Self> {
+        let name = settings.preset.as_deref().unwrap_or("default");
+        let mut bindings = Self::by_name(name)
+            .ok_or_else(|| invalid(format!("unknown key preset '{}', expected one of {:?}", name, BINDING_PRESETS)))?;
+
+        let overrides = [
+            (Action::MoveUp, &settings.move_up),
+            (Action::MoveDown, &settings.move_down),
+            (Action::MoveLeft, &settings.move_left),
+            (Action::MoveRight, &settings.move_right),
+            (Action::Pause, &settings.pause),
+            (Action::Confirm, &settings.confirm),
+            (Action::Quit, &settings.quit),
+            (Action::Achievements, &settings.achievements),
+            (Action::Hint, &settings.hint),
+        ];
+        for (action, names) in overrides {
+            if let Some(names) = names {
+                let keys = names.iter().map(|name| parse_key(name)).collect::<Result<Vec<_>>>()?;
+                bindings.bind(action, keys);
+            }
+        }
+
+        if let Some(players) = &settings.players {
+            if players.len() > MAX_PLAYERS {
+                return Err(invalid(format!("at most {} player key sets are supported", MAX_PLAYERS)));
+            }
+            for (player, names) in players.iter().enumerate() {
+                let keys = names.iter().map(|name| parse_key(name)).collect::<Result<Vec<_>>>()?;
+                let keys: [KeyCode; 4] = keys.try_into()
+                    .map_err(|_| invalid(format!("player {} needs exactly four keys: up, down, left, right", player + 1)))?;
+                bindings.bind_player(player, keys);
+            }
+        }
+
+        bindings.check_conflicts()?;
+        Ok(bindings)
+    }
+
+    // Replaces every key for `action`
+    pub fn bind(&mut self, action: Action, keys: Vec<KeyCode>) {
+        self.keys.insert(action, keys.into_iter().map(normalize).collect());
+    }
+
+    // Replaces a player's up, down, left and right keys
+    pub fn bind_player(&mut self, player: usize, keys: [KeyCode; 4]) {
+        if let Some(slot) = self.players.get_mut(player) {
+            *slot = keys.map(normalize);
+        }
+    }
+
+    pub fn player_keys(&self, player: usize) -> Option<&[KeyCode; 4]> {
+        self.players.get(player)
+    }
+
+    // Which player steers with this key in a match, and where to
+    pub fn player_move(&self, key: KeyCode) -> Option<(usize, Direction)> {
+        let key = normalize(key);
+        self.players.iter().enumerate().find_map(|(player, keys)| {
+            keys.iter().position(|k| *k == key).map(|i| (player, PLAYER_DIRECTIONS[i]))
+        })
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.keys.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn action(&self, key: KeyCode, context: Context) -> Option<Action> {
+        let key = normalize(key);
+        Action::ALL.into_iter()
+            .filter(|action| action.contexts().contains(&context))
+            .find(|action| self.keys(*action).contains(&key))
+    }
+
+    // Label for hints such as "Press SPACE to continue"
+    pub fn label(&self, action: Action) -> String {
+        self.keys(action).first().map_or("?".to_string(), |key| key_name(*key))
+    }
+
+    pub fn check_conflicts(&self) -> Result<()> {
+        for (i, first) in Action::ALL.iter().enumerate() {
+            for second in &Action::ALL[i + 1..] {
+                let shared_context = first.contexts().iter().any(|c| second.contexts().contains(c));
+                let clash = self.keys(*first).iter().find(|key| self.keys(*second).contains(key));
+
+                if let (true, Some(key)) = (shared_context, clash) {
+                    return Err(invalid(format!(
+                        "key '{}' is bound to both {} and {}",
+                        key_name(*key),
+                        first.name(),
+                        second.name()
+                    )));
+                }
+            }
+        }
+
+        // Match keys replace the move actions, but must not clash with each other or the rest
+        let player_keys = self.players.iter().enumerate()
+            .flat_map(|(player, keys)| keys.iter().map(move |key| (player, *key)));
+        let mut seen: Vec<(usize, KeyCode)> = Vec::new();
+        for (player, key) in player_keys {
+            if let Some((other, _)) = seen.iter().find(|(_, k)| *k == key) {
+                return Err(invalid(format!(
+                    "key '{}' is bound to both player {} and player {}",
+                    key_name(key),
+                    other + 1,
+                    player + 1
+                )));
+            }
+            let action = Action::ALL.into_iter()
+                .filter(|action| action.contexts().contains(&Context::Game) && !action.is_move())
+                .find(|action| self.keys(*action).contains(&key));
+            if let Some(action) = action {
+                return Err(invalid(format!(
+                    "key '{}' is bound to both player {} and {}",
+                    key_name(key),
+                    player + 1,
+                    action.name()
+                )));
+            }
+            seen.push((player, key));
+        }
+
+        if let Some(action) = Action::ALL.iter().find(|action| self.keys(**action).is_empty()) {
+            return Err(invalid(format!("no key is bound to {}", action.name())));
+        }
+        Ok(())
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::by_name("default").expect("default key bindings exist")
+    }
+}
+
+// Letters match regardless of case or shift
+fn normalize(key: KeyCode) -> KeyCode {
+    match key {
+        KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+        other => other,
+    }
+}
+
+// Accepts single characters and names like "up", "enter", "space" or "f5"
+pub fn parse_key(name: &str) -> Result<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(normalize(KeyCode::Char(c)));
+    }
+
+    let key = match name.to_ascii_lowercase().as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "enter" | "return" => KeyCode::Enter,
+        "space" => KeyCode::Char(' '),
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        other => match other.strip_prefix('f').and_then(|n| n.parse().ok()) {
+            Some(n @ 1..=12) => KeyCode::F(n),
+            _ => return Err(invalid(format!("unknown key '{}'", name))),
+        },
+    };
+    Ok(key)
+}
+
+pub fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "SPACE".to_string(),
+        KeyCode::Char(c) => format!("'{}'", c),
+        KeyCode::Enter => "ENTER".to_string(),
+        KeyCode::Esc => "ESC".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other).to_uppercase(),
+    }
+}
+
+fn invalid(message: String) -> GameError {
+    GameError::Config(config::ConfigError::Message(message))
+}
@@ -0,0 +1,123 @@
+// src/ui/buffer.rs
+use crossterm::{
+    style::{Color, SetForegroundColor, SetBackgroundColor, Print},
+    cursor::MoveTo,
+    QueueableCommand,
+};
+use std::io::Write;
+use crate::utils::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Color,
+    pub bg: Color,
+}
+
+impl Cell {
+    pub fn new(ch: char, fg: Color, bg: Color) -> Self {
+        Self { ch, fg, bg }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ', Color::Reset, Color::Reset)
+    }
+}
+
+// Frames are composed in the back buffer; `present` sends only the cells that
+// differ from what is already on screen (the front buffer) in a single flush.
+pub struct FrameBuffer {
+    width: u16,
+    height: u16,
+    back: Vec<Cell>,
+    front: Vec<Cell>,
+    full_redraw: bool,
+}
+
+impl FrameBuffer {
+    pub fn new(width: u16, height: u16) -> Self {
+        let size = usize::from(width) * usize::from(height);
+        Self {
+            width,
+            height,
+            back: vec![Cell::default(); size],
+            front: vec![Cell::default(); size],
+            full_redraw: true,
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| usize::from(y) * usize::from(self.width) + usize::from(x))
+    }
+
+    pub fn clear(&mut self) {
+        self.back.fill(Cell::default());
+    }
+
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if let Some(i) = self.index(x, y) {
+            self.back[i] = cell;
+        }
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        self.index(x, y).map(|i| &self.back[i])
+    }
+
+    // Forget what is on screen, e.g. after the terminal was cleared or resized
+    pub fn invalidate(&mut self) {
+        self.full_redraw = true;
+    }
+
+    // Cells that differ from what is on screen, in row-major order
+    pub fn changes(&self) -> impl Iterator<Item = (u16, u16, Cell)> + '_ {
+        let width = usize::from(self.width);
+        self.back.iter().zip(&self.front).enumerate()
+            .filter(move |(_, (back, front))| self.full_redraw || back != front)
+            .map(move |(i, (back, _))| ((i % width) as u16, (i / width) as u16, *back))
+    }
+
+    // Record the back buffer as what is now on screen
+    pub fn commit(&mut self) {
+        self.front.copy_from_slice(&self.back);
+        self.full_redraw = false;
+    }
+
+    pub fn present(&mut self, out: &mut impl Write) -> Result<()> {
+        let mut cursor: Option<(u16, u16)> = None;
+        let mut colors: Option<(Color, Color)> = None;
+
+        for (x, y, cell) in self.changes() {
+            // Printing advances the cursor, so runs of changed cells need one move
+            if cursor != Some((x, y)) {
+                out.queue(MoveTo(x, y))?;
+            }
+            if colors != Some((cell.fg, cell.bg)) {
+                out.queue(SetForegroundColor(cell.fg))?
+                    .queue(SetBackgroundColor(cell.bg))?;
+                colors = Some((cell.fg, cell.bg));
+            }
+            out.queue(Print(cell.ch))?;
+            cursor = Some((x + 1, y));
+        }
+
+        if colors.is_some() {
+            out.queue(SetForegroundColor(Color::Reset))?
+                .queue(SetBackgroundColor(Color::Reset))?;
+        }
+        out.flush()?;
+
+        self.commit();
+        Ok(())
+    }
+}
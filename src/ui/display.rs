@@ -1,125 +1,191 @@
 // src/ui/display.rs
-use crossterm::{
-    style::{Color, SetForegroundColor, SetBackgroundColor},
-    cursor::MoveTo,
-    QueueableCommand,
-};
-use std::io::{Stdout, Write};
-use crate::utils::Result;
+use crossterm::style::Color;
 use crate::entities::Point;
+use crate::ui::{Cell, FrameBuffer};
 
 pub struct DisplayManager {
     width: u16,
     height: u16,
+    origin: (u16, u16),  // Where the board's top-left corner sits in the terminal
+    square_cells: bool,  // Two board rows per terminal row, drawn with half blocks
 }
 
 impl DisplayManager {
     pub fn new(width: u16, height: u16) -> Self {
-        Self { width, height }
+        Self { width, height, origin: (0, 0), square_cells: false }
     }
 
-    pub fn clear_screen(&self, stdout: &mut Stdout) -> Result<()> {
-        // Simple clear - just fill with spaces
-        for y in 0..=self.height {
-            for x in 0..self.width {
-                stdout
-                    .queue(MoveTo(x, y))?
-                    .queue(SetBackgroundColor(Color::Reset))?
-                    .queue(crossterm::style::Print(" "))?;
-            }
+    pub fn set_square_cells(&mut self, enabled: bool) {
+        self.square_cells = enabled;
+    }
+
+    pub fn square_cells(&self) -> bool {
+        self.square_cells
+    }
+
+    // Terminal rows taken by the board, not counting the status bar
+    pub fn rows(&self) -> u16 {
+        if self.square_cells {
+            self.height.div_ceil(2)
+        } else {
+            self.height
         }
-        stdout.flush()?;
-        Ok(())
+    }
+
+    // Terminal position of a board point
+    pub fn screen_position(&self, point: &Point) -> (u16, u16) {
+        let row = if self.square_cells { point.y / 2 } else { point.y };
+        (self.origin.0 + point.x, self.origin.1 + row)
+    }
+
+    pub fn set_origin(&mut self, x: u16, y: u16) {
+        self.origin = (x, y);
+    }
+
+    pub fn origin(&self) -> (u16, u16) {
+        self.origin
+    }
+
+    fn put(&self, buffer: &mut FrameBuffer, x: u16, y: u16, cell: Cell) {
+        buffer.set(self.origin.0 + x, self.origin.1 + y, cell);
+    }
+
+    pub fn clear_screen(&self, buffer: &mut FrameBuffer) {
+        buffer.clear();
     }
 
     pub fn draw_char(
         &self,
-        stdout: &mut Stdout,
+        buffer: &mut FrameBuffer,
         point: &Point,
         ch: char,
         fg: Color,
         bg: Color
-    ) -> Result<()> {
-        if point.x < self.width && point.y < self.height {
-            stdout
-                .queue(MoveTo(point.x, point.y))?
-                .queue(SetForegroundColor(fg))?
-                .queue(SetBackgroundColor(bg))?
-                .queue(crossterm::style::Print(ch))?;
+    ) {
+        self.draw_cell(buffer, point, Cell::new(ch, fg, bg));
+    }
+
+    pub fn draw_cell(&self, buffer: &mut FrameBuffer, point: &Point, cell: Cell) {
+        if point.x >= self.width || point.y >= self.height {
+            return;
+        }
+
+        if !self.square_cells {
+            self.put(buffer, point.x, point.y, cell);
+            return;
+        }
+
+        // Only colour survives in half-block mode; keep whatever the other half shows
+        let (x, y) = self.screen_position(point);
+        let (mut top, mut bottom) = buffer.get(x, y).map_or((Color::Reset, Color::Reset), |cell| halves(*cell));
+        if point.y & 1 == 0 {
+            top = visible_color(cell);
+        } else {
+            bottom = visible_color(cell);
+        }
+        buffer.set(x, y, half_block(top, bottom));
+    }
+
+    pub fn draw_text(
+        &self,
+        buffer: &mut FrameBuffer,
+        point: &Point,
+        text: &str,
+        fg: Color,
+        bg: Color
+    ) {
+        if point.y > self.rows() {
+            return;
+        }
+        for (i, ch) in text.chars().enumerate() {
+            let x = point.x + i as u16;
+            if x >= self.width {
+                break;
+            }
+            self.put(buffer, x, point.y, Cell::new(ch, fg, bg));
         }
-        Ok(())
     }
 
     pub fn draw_centered_box(
         &self,
-        stdout: &mut Stdout,
+        buffer: &mut FrameBuffer,
         text: &str,
         bg_color: Color,
         fg_color: Color,
-    ) -> Result<()> {
+    ) {
         let lines: Vec<&str> = text.split('\n').collect();
-        let y_start = (self.height / 2) - (lines.len() as u16 / 2);
-
-        let max_width = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u16;
         let padding = 2;
-        let box_width = max_width + (padding * 2);
-        let box_height = lines.len() as u16 + (padding * 2);
+
+        // Boxes wider or taller than the board are clipped rather than overflowing
+        let max_width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0) as u16;
+        let box_width = (max_width + (padding * 2)).min(self.width);
+        let box_height = (lines.len() as u16 + (padding * 2)).min(self.rows());
         let box_x = (self.width - box_width) / 2;
-        let box_y = y_start - padding;
+        let box_y = (self.rows() - box_height) / 2;
+        let y_start = box_y + padding;
 
         // Draw box background
         for y in 0..box_height {
             for x in 0..box_width {
-                stdout
-                    .queue(MoveTo(box_x + x, box_y + y))?
-                    .queue(SetBackgroundColor(bg_color))?
-                    .queue(crossterm::style::Print(" "))?;
+                self.put(buffer, box_x + x, box_y + y, Cell::new(' ', fg_color, bg_color));
             }
         }
 
         // Draw text
         for (i, line) in lines.iter().enumerate() {
-            let x = (self.width - line.len() as u16) / 2;
             let y = y_start + i as u16;
+            if y >= box_y + box_height {
+                break;
+            }
+            let x = self.width.saturating_sub(line.chars().count() as u16) / 2;
 
-            stdout
-                .queue(MoveTo(x, y))?
-                .queue(SetForegroundColor(fg_color))?
-                .queue(SetBackgroundColor(bg_color))?
-                .queue(crossterm::style::Print(line))?;
+            for (offset, ch) in line.chars().take(usize::from(self.width)).enumerate() {
+                self.put(buffer, x + offset as u16, y, Cell::new(ch, fg_color, bg_color));
+            }
         }
-
-        stdout.queue(SetBackgroundColor(Color::Reset))?;
-        stdout.queue(SetForegroundColor(Color::Reset))?;
-        stdout.flush()?;
-        Ok(())
     }
 
     pub fn draw_status_bar(
         &self,
-        stdout: &mut Stdout,
+        buffer: &mut FrameBuffer,
         text: &str,
         bg_color: Color,
         fg_color: Color,
-    ) -> Result<()> {
+    ) {
         // Fill entire bottom line with background color
         for x in 0..self.width {
-            stdout
-                .queue(MoveTo(x, self.height))?
-                .queue(SetBackgroundColor(bg_color))?
-                .queue(crossterm::style::Print(" "))?;
+            self.put(buffer, x, self.rows(), Cell::new(' ', fg_color, bg_color));
         }
 
-        // Draw the text
-        stdout
-            .queue(MoveTo(2, self.height))?
-            .queue(SetForegroundColor(fg_color))?
-            .queue(SetBackgroundColor(bg_color))?
-            .queue(crossterm::style::Print(text))?;
+        // Draw the text, cut to the bar width
+        for (i, ch) in text.chars().take(self.width.saturating_sub(2) as usize).enumerate() {
+            self.put(buffer, 2 + i as u16, self.rows(), Cell::new(ch, fg_color, bg_color));
+        }
+    }
+}
 
-        stdout.queue(SetBackgroundColor(Color::Reset))?;
-        stdout.queue(SetForegroundColor(Color::Reset))?;
-        stdout.flush()?;
-        Ok(())
+// The colour a cell mostly shows: its background if it has one, else its glyph
+fn visible_color(cell: Cell) -> Color {
+    if cell.bg == Color::Reset { cell.fg } else { cell.bg }
+}
+
+// Top and bottom colours of a terminal cell in half-block mode
+fn halves(cell: Cell) -> (Color, Color) {
+    match cell.ch {
+        '▀' => (cell.fg, cell.bg),
+        '▄' => (cell.bg, cell.fg),
+        '█' => (cell.fg, cell.fg),
+        _ => (cell.bg, cell.bg),
     }
-}
\ No newline at end of file
+}
+
+fn half_block(top: Color, bottom: Color) -> Cell {
+    if top == bottom {
+        Cell::new(' ', Color::Reset, top)
+    } else if top == Color::Reset {
+        // Keep the terminal's own background showing through the empty half
+        Cell::new('▄', bottom, Color::Reset)
+    } else {
+        Cell::new('▀', top, bottom)
+    }
+}
@@ -0,0 +1,101 @@
+// src/ui/hud.rs
+use crate::{
+    core::GameState,
+    gameplay::{GameMode, Objective, WallMode},
+};
+
+// The side panel only appears when the terminal has room for it next to the board
+pub const SIDE_PANEL_MIN_WIDTH: u16 = 14;
+pub const SIDE_PANEL_MAX_WIDTH: u16 = 24;
+pub const SIDE_PANEL_GAP: u16 = 2;
+
+// Width the panel gets beside a board of `board_width`, if it fits at all
+pub fn side_panel_width(columns: u16, board_width: u16) -> Option<u16> {
+    let available = columns.saturating_sub(board_width + SIDE_PANEL_GAP);
+    (available >= SIDE_PANEL_MIN_WIDTH).then(|| available.min(SIDE_PANEL_MAX_WIDTH))
+}
+
+// e.g. "[=====-----]" for half way there
+pub fn progress_bar(current: u32, target: u32, width: u16) -> String {
+    let inner = usize::from(width.saturating_sub(2));
+    let filled = match target {
+        0 => inner,
+        _ => (inner * current.min(target) as usize) / target as usize,
+    };
+    format!("[{}{}]", "=".repeat(filled), "-".repeat(inner - filled))
+}
+
+fn row(label: &str, value: &str, width: u16) -> String {
+    let width = usize::from(width);
+    let padding = width.saturating_sub(label.chars().count());
+    let line = format!("{}{:>padding$}", label, value, padding = padding);
+    line.chars().take(width).collect()
+}
+
+fn modifiers(game_state: &GameState) -> Vec<String> {
+    let mut modifiers = Vec::new();
+    if game_state.mode() == GameMode::Endless {
+        modifiers.push("endless".to_string());
+    }
+    if game_state.wall_mode() == WallMode::Wrap {
+        modifiers.push("wrap-around walls".to_string());
+    }
+    if game_state.uses_exit_gate() {
+        modifiers.push("exit gate".to_string());
+    }
+    if game_state.score_multiplier() > 1 {
+        modifiers.push(format!("score x{}", game_state.score_multiplier()));
+    }
+    modifiers
+}
+
+pub fn side_panel_lines(game_state: &GameState, best_score: Option<u32>, width: u16) -> Vec<String> {
+    let level = match game_state.mode() {
+        GameMode::Classic => format!("{}/{}", game_state.current_level(), game_state.max_levels()),
+        GameMode::Endless => game_state.current_level().to_string(),
+    };
+
+    // The bar follows the level's objective, which is the score target by default
+    let progress = game_state.objective_progress();
+    let goal = match game_state.objective() {
+        Objective::Score => match game_state.score_needed_for_next() {
+            Some(next) => format!("{}/{}", game_state.score(), next),
+            None => game_state.score().to_string(),
+        },
+        _ => format!("{}/{}", progress.current, progress.target),
+    };
+
+    let seconds = game_state.level_elapsed_ms() / 1000;
+    let mut lines = vec![
+        row("Level", &level, width),
+        row("Goal", &goal, width),
+        progress_bar(progress.current, progress.target, width),
+        String::new(),
+        row("Time", &format!("{}:{:02}", seconds / 60, seconds % 60), width),
+        row("Score", &game_state.score().to_string(), width),
+        row("Best", &best_score.map_or("-".to_string(), |best| best.to_string()), width),
+        row("Length", &game_state.snake().body().len().to_string(), width),
+        row("Tick", &format!("{} ms", game_state.get_tick_rate()), width),
+    ];
+
+    if game_state.players().len() > 1 {
+        lines.push(String::new());
+        lines.push(row("Players", &format!("to {}", game_state.match_target()), width));
+        for (i, player) in game_state.players().iter().enumerate() {
+            let score = match player.is_alive() {
+                true => player.score().to_string(),
+                false => format!("{} out", player.score()),
+            };
+            lines.push(row(&format!(" P{}", i + 1), &score, width));
+        }
+    }
+
+    let modifiers = modifiers(game_state);
+    if !modifiers.is_empty() {
+        lines.push(String::new());
+        lines.push(row("Modifiers", "", width));
+        lines.extend(modifiers.iter().map(|m| row(&format!(" {}", m), "", width)));
+    }
+
+    lines
+}
@@ -1,6 +1,63 @@
-use crossterm::event::{poll, read, Event, KeyCode};
-use std::time::Duration;
-use crate::utils::Result;
+use crossterm::event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use crate::utils::{Result, GameError};
+
+// How often the input thread checks whether it should stop
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEvent {
+    Key {
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        kind: KeyEventKind,
+    },
+    Resize(u16, u16),
+    FocusGained,
+    FocusLost,
+}
+
+impl InputEvent {
+    // Pastes and mouse events have no meaning in the game and are dropped
+    pub fn from_event(event: Event) -> Option<Self> {
+        match event {
+            Event::Key(key) => Some(InputEvent::Key {
+                code: key.code,
+                modifiers: key.modifiers,
+                kind: key.kind,
+            }),
+            Event::Resize(columns, rows) => Some(InputEvent::Resize(columns, rows)),
+            Event::FocusGained => Some(InputEvent::FocusGained),
+            Event::FocusLost => Some(InputEvent::FocusLost),
+            _ => None,
+        }
+    }
+
+    // The key for presses and auto-repeats; releases are ignored
+    pub fn pressed_key(&self) -> Option<KeyCode> {
+        match self {
+            InputEvent::Key { code, kind: KeyEventKind::Press | KeyEventKind::Repeat, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    // Raw mode swallows the usual SIGINT, so Ctrl+C has to be handled by hand
+    pub fn is_interrupt(&self) -> bool {
+        matches!(
+            self,
+            InputEvent::Key { code: KeyCode::Char('c'), modifiers, kind: KeyEventKind::Press }
+                if modifiers.contains(KeyModifiers::CONTROL)
+        )
+    }
+}
 
 pub struct InputHandler;
 
@@ -9,12 +66,105 @@ impl InputHandler {
         Self
     }
 
-    pub fn get_input(&self) -> Result<Option<KeyCode>> {
-        if poll(Duration::from_millis(0))? {
-            if let Event::Key(key_event) = read()? {
-                return Ok(Some(key_event.code));
+    // Everything that arrived since the last call, oldest first, without blocking
+    pub fn poll_events(&self) -> Result<Vec<InputEvent>> {
+        let mut events = Vec::new();
+        while poll(Duration::from_millis(0))? {
+            if let Some(event) = InputEvent::from_event(read()?) {
+                events.push(event);
             }
         }
-        Ok(None)
+        Ok(events)
+    }
+}
+
+impl Default for InputHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// An input event and when the input thread read it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedEvent {
+    pub event: InputEvent,
+    pub at: Instant,
+}
+
+// Reads terminal events on its own thread so key presses are stamped when they
+// arrive rather than when the game loop gets round to them
+pub struct InputThread {
+    receiver: Receiver<Result<TimedEvent>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl InputThread {
+    pub fn spawn() -> Self {
+        Self::spawn_with(|timeout| {
+            if poll(timeout)? {
+                Ok(Some(read()?))
+            } else {
+                Ok(None)
+            }
+        })
+    }
+
+    // `source` waits up to the given time for the next event
+    pub fn spawn_with<F>(mut source: F) -> Self
+    where
+        F: FnMut(Duration) -> Result<Option<Event>> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopping = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            while !stopping.load(Ordering::Relaxed) {
+                let message = match source(INPUT_POLL_INTERVAL) {
+                    Ok(Some(event)) => match InputEvent::from_event(event) {
+                        Some(event) => Ok(TimedEvent { event, at: Instant::now() }),
+                        None => continue,
+                    },
+                    Ok(None) => continue,
+                    Err(e) => Err(e),
+                };
+
+                // Errors end the thread; the game loop reports them
+                let failed = message.is_err();
+                if sender.send(message).is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        Self { receiver, stop, handle: Some(handle) }
+    }
+
+    // Waits until `deadline` for the first event, then takes whatever else is queued
+    pub fn events_until(&self, deadline: Instant) -> Result<Vec<TimedEvent>> {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let first = match self.receiver.recv_timeout(timeout) {
+            Ok(message) => message?,
+            Err(RecvTimeoutError::Timeout) => return Ok(Vec::new()),
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(GameError::InvalidInput("input thread stopped".to_string()));
+            }
+        };
+
+        let mut events = vec![first];
+        for message in self.receiver.try_iter() {
+            events.push(message?);
+        }
+        Ok(events)
+    }
+}
+
+impl Drop for InputThread {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,23 @@
 mod input;
 mod renderer;
 mod display;
+mod buffer;
+mod theme;
+mod sprites;
+mod backend;
+mod text;
+mod hud;
+mod animation;
+mod bindings;
 
-pub use input::InputHandler;
-pub use renderer::Renderer;
-pub use display::DisplayManager;
\ No newline at end of file
+pub use input::{InputEvent, InputHandler, InputThread, TimedEvent};
+pub use renderer::{Renderer, Hint};
+pub use display::DisplayManager;
+pub use buffer::{Cell, FrameBuffer};
+pub use theme::{Theme, SnakeGlyphs, THEME_NAMES, parse_color};
+pub use backend::{RenderBackend, CrosstermBackend, MemoryBackend};
+pub use text::{render_text, render_text_with_theme};
+pub use hud::{side_panel_width, side_panel_lines, progress_bar, SIDE_PANEL_MIN_WIDTH, SIDE_PANEL_MAX_WIDTH, SIDE_PANEL_GAP};
+pub use animation::{Animation, Animator, countdown_label, FLASH_INTERVAL};
+pub use bindings::{Action, Context, KeyBindings, BINDING_PRESETS, parse_key, key_name};
+pub use sprites::{SegmentRole, segment_roles, direction_between};
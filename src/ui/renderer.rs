@@ -1,211 +1,650 @@
 // renderer.rs
 use crate::{
     utils::Result,
-    core::GameState,
+    core::{GameState, AchievementTracker, ACHIEVEMENTS},
     entities::{Point, Obstacle},
-    gameplay::{GameState as GameStateEnum, GameEndReason},
-    ui::DisplayManager,
+    gameplay::{GameState as GameStateEnum, GameEndReason, GameMode, Objective},
+    ui::{
+        Cell, DisplayManager, FrameBuffer, Theme, RenderBackend, CrosstermBackend, segment_roles,
+        side_panel_width, side_panel_lines, SIDE_PANEL_GAP,
+        Animation, Animator, countdown_label, FLASH_INTERVAL,
+        Action, KeyBindings,
+    },
     config::BORDER_THICKNESS,
 };
-use crossterm::{
-    cursor::{Hide, Show},
-    execute,
-    style::Color,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, enable_raw_mode, disable_raw_mode},
-};
-use std::io::{stdout, Stdout};
+use crossterm::style::Color;
+use std::time::{Duration, Instant};
+
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+// Suggested route to the food, drawn under the snake
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hint {
+    Off,
+    /// Squares to visit, next step first, ending on the food.
+    Path(Vec<Point>),
+    /// Going for the food now would trap the snake.
+    NoSafePath,
+}
 
-pub struct Renderer {
+pub struct Renderer<B: RenderBackend = CrosstermBackend> {
     dimensions: (u16, u16),
-    stdout: Stdout,
+    terminal: (u16, u16),
+    backend: B,
+    buffer: FrameBuffer,
     display_manager: DisplayManager,
+    best_score: Option<u32>,
+    toast: Option<(String, Instant)>,
+    theme: Theme,
+    side_panel: bool,
+    animator: Animator,
+    bindings: KeyBindings,  // For key hints in dialogs
+    hint: Hint,
+    initialised: bool,  // Whether the terminal needs putting back
 }
 
 impl Renderer {
     pub fn new(width: u16, height: u16) -> Self {
-        Self {
+        Self::with_backend(CrosstermBackend::new(), width, height)
+    }
+}
+
+impl<B: RenderBackend> Renderer<B> {
+    pub fn with_backend(backend: B, width: u16, height: u16) -> Self {
+        // Without a terminal to ask, assume the board fits exactly (extra row for the status bar)
+        let terminal = backend.size().unwrap_or((width, height + 1));
+        let mut renderer = Self {
             dimensions: (width, height),
-            stdout: stdout(),
+            terminal,
+            backend,
+            buffer: FrameBuffer::new(terminal.0, terminal.1),
             display_manager: DisplayManager::new(width, height),
+            best_score: None,
+            toast: None,
+            theme: Theme::default(),
+            side_panel: false,
+            animator: Animator::new(),
+            bindings: KeyBindings::default(),
+            hint: Hint::Off,
+            initialised: false,
+        };
+        renderer.center_board();
+        renderer
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    // Board and side panel are centered together
+    fn center_board(&mut self) {
+        let panel = self.side_panel_width().map_or(0, |width| width + SIDE_PANEL_GAP);
+        let x = self.terminal.0.saturating_sub(self.dimensions.0 + panel) / 2;
+        let y = self.terminal.1.saturating_sub(self.display_manager.rows() + 1) / 2;
+        self.display_manager.set_origin(x, y);
+    }
+
+    // Packs two board rows into each terminal row so cells look square
+    pub fn set_square_cells(&mut self, enabled: bool) {
+        self.display_manager.set_square_cells(enabled);
+        self.buffer.clear();
+        self.center_board();
+    }
+
+    pub fn set_side_panel(&mut self, enabled: bool) {
+        self.side_panel = enabled;
+        self.center_board();
+    }
+
+    fn side_panel_width(&self) -> Option<u16> {
+        if !self.side_panel {
+            return None;
         }
+        side_panel_width(self.terminal.0, self.dimensions.0)
+    }
+
+    pub fn set_bindings(&mut self, bindings: KeyBindings) {
+        self.bindings = bindings;
+    }
+
+    pub fn set_hint(&mut self, hint: Hint) {
+        self.hint = hint;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn terminal_size(&self) -> (u16, u16) {
+        self.terminal
+    }
+
+    pub fn fits_terminal(&self) -> bool {
+        self.dimensions.0 <= self.terminal.0 && self.display_manager.rows() < self.terminal.1
+    }
+
+    pub fn handle_terminal_resize(&mut self, columns: u16, rows: u16) {
+        self.terminal = (columns, rows);
+        self.buffer = FrameBuffer::new(columns, rows);
+        self.center_board();
+    }
+
+    // Queued after any animation that is already playing
+    pub fn play(&mut self, animation: Animation) {
+        self.animator.play(animation, Instant::now());
+    }
+
+    // True while a wipe or countdown should keep the snake still
+    pub fn holds_play(&mut self) -> bool {
+        self.animator.holds_play(Instant::now())
+    }
+
+    pub fn stop_animations(&mut self) {
+        self.animator.clear();
+    }
+
+    pub fn is_animating(&mut self) -> bool {
+        !self.animator.is_idle(Instant::now())
+    }
+
+    // Short message shown over the top border for a few seconds
+    pub fn show_toast(&mut self, text: String) {
+        self.toast = Some((text, Instant::now()));
+    }
+
+    pub fn render_achievements(&mut self, tracker: &AchievementTracker) -> Result<()> {
+        let mut text = format!("Achievements {}/{}\n", tracker.unlocked_count(), ACHIEVEMENTS.len());
+        for achievement in ACHIEVEMENTS {
+            let mark = if tracker.is_unlocked(achievement.id) { 'x' } else { ' ' };
+            text.push_str(&format!("\n[{}] {}\n    {}", mark, achievement.name, achievement.description));
+        }
+        text.push_str("\n\nPress any key to go back");
+
+        self.display_manager.clear_screen(&mut self.buffer);
+        self.display_manager.draw_centered_box(
+            &mut self.buffer,
+            &text,
+            self.theme.dialog.bg,
+            self.theme.dialog.fg,
+        );
+        self.backend.draw(&mut self.buffer)
+    }
+
+    pub fn resize(&mut self, width: u16, height: u16) {
+        let square_cells = self.display_manager.square_cells();
+        self.dimensions = (width, height);
+        self.display_manager = DisplayManager::new(width, height);
+        self.display_manager.set_square_cells(square_cells);
+        self.buffer.clear();
+        self.center_board();
+    }
+
+    pub fn set_best_score(&mut self, best_score: Option<u32>) {
+        self.best_score = best_score;
+    }
+
+    pub fn render_menu(&mut self, title: &str, options: &[String], selected: usize) -> Result<()> {
+        let mut text = format!("{}\n", title);
+        for (i, option) in options.iter().enumerate() {
+            let marker = if i == selected { '>' } else { ' ' };
+            text.push_str(&format!("\n{} {}. {}", marker, i + 1, option));
+        }
+        text.push_str(&format!(
+            "\n\n{} to start, {} achievements\n{} to quit",
+            self.bindings.label(Action::Confirm),
+            self.bindings.label(Action::Achievements),
+            self.bindings.label(Action::Quit),
+        ));
+
+        self.display_manager.clear_screen(&mut self.buffer);
+        self.display_manager.draw_centered_box(
+            &mut self.buffer,
+            &text,
+            self.theme.dialog.bg,
+            self.theme.dialog.fg,
+        );
+        self.backend.draw(&mut self.buffer)
     }
 
     pub fn init(&mut self) -> Result<()> {
-        enable_raw_mode()?;
-        execute!(
-            self.stdout,
-            EnterAlternateScreen,
-            Hide,
-        )?;
+        self.backend.init()?;
+        self.initialised = true;
         Ok(())
     }
 
+    // Safe to call more than once; dropping the renderer calls it too
     pub fn cleanup(&mut self) -> Result<()> {
-        execute!(
-            self.stdout,
-            Show,
-            LeaveAlternateScreen,
-        )?;
-        disable_raw_mode()?;
-        Ok(())
+        if !std::mem::take(&mut self.initialised) {
+            return Ok(());
+        }
+        self.backend.cleanup()
     }
 
     pub fn render(&mut self, game_state: &GameState) -> Result<()> {
+        if !self.fits_terminal() {
+            self.draw_too_small();
+            return self.backend.draw(&mut self.buffer);
+        }
+
+        // The board stays visible underneath pause, transition and game over boxes
+        self.draw_board(game_state);
+        self.draw_side_panel(game_state);
+
         match game_state.game_state() {
-            GameStateEnum::Playing => {
-                self.display_manager.clear_screen(&mut self.stdout)?;
-                
-                // Draw borders
-                self.draw_borders()?;
-                
-                // Draw obstacles
-                self.draw_obstacles(game_state.obstacles())?;
-                
-                // Draw snake
-                for point in game_state.snake().body() {
-                    self.display_manager.draw_char(&mut self.stdout, point, '█', Color::Green, Color::Reset)?;
-                }
-                
-                // Draw food
-                self.display_manager.draw_char(
-                    &mut self.stdout, 
-                    game_state.food().position(), 
-                    '●', 
-                    Color::Red, 
-                    Color::Reset
-                )?;
-                
-                // Draw status
-                self.draw_status(game_state)?;
+            GameStateEnum::Playing => {}
+            GameStateEnum::Paused => {
+                self.display_manager.draw_centered_box(
+                    &mut self.buffer,
+                    "PAUSED",
+                    self.theme.dialog.bg,
+                    self.theme.dialog.fg,
+                );
             }
             GameStateEnum::LevelTransition => {
-                self.draw_transition(game_state)?;
+                self.draw_transition(game_state);
             }
             GameStateEnum::GameOver(reason) => {
-                self.draw_game_over(game_state, reason)?;
+                self.draw_game_over(game_state, reason);
             }
         }
-        Ok(())
+
+        // Achievements can unlock as a level ends, so toasts show over every state
+        self.draw_toast();
+
+        // Animations go on top of everything else
+        if let Some((animation, elapsed)) = self.animator.current(Instant::now()) {
+            self.draw_animation(game_state, animation, elapsed);
+        }
+
+        self.backend.draw(&mut self.buffer)
+    }
+
+    fn draw_board(&mut self, game_state: &GameState) {
+        self.display_manager.clear_screen(&mut self.buffer);
+        
+        // Draw borders
+        self.draw_borders();
+        
+        // Draw obstacles
+        self.draw_obstacles(game_state.obstacles());
+        
+        // Draw the hint path; the snake and food cover its ends
+        if let Hint::Path(path) = &self.hint {
+            for point in path {
+                self.display_manager.draw_cell(&mut self.buffer, point, self.theme.hint);
+            }
+        }
+
+        // Draw snakes
+        self.draw_snakes(game_state);
+        
+        // Draw food
+        self.display_manager.draw_cell(&mut self.buffer, game_state.food().position(), self.theme.food);
+
+        // Draw objective pickups, numbered when order matters
+        self.draw_pickups(game_state);
+
+        if let Some(gate) = game_state.exit_gate() {
+            self.display_manager.draw_cell(&mut self.buffer, gate, self.theme.gate);
+        }
+        
+        // Draw status
+        self.draw_status(game_state);
     }
 
-    fn draw_borders(&mut self) -> Result<()> {
+    fn draw_animation(&mut self, game_state: &GameState, animation: Animation, elapsed: Duration) {
+        match animation {
+            Animation::Wipe => {
+                // A band as wide as an eighth of the board, entering on the left and leaving on the right
+                let band = (self.dimensions.0 / 8).max(2);
+                let travel = u32::from(self.dimensions.0 + band);
+                let front = (travel * elapsed.as_millis() as u32 / animation.duration().as_millis() as u32) as u16;
+                let cell = Cell::new(' ', self.theme.dialog.fg, self.theme.dialog.bg);
+
+                for x in front.saturating_sub(band)..front.min(self.dimensions.0) {
+                    for y in 0..self.dimensions.1 {
+                        self.display_manager.draw_cell(&mut self.buffer, &Point::new(x, y), cell);
+                    }
+                }
+            }
+            Animation::Countdown => {
+                self.display_manager.draw_centered_box(
+                    &mut self.buffer,
+                    countdown_label(elapsed),
+                    self.theme.dialog.bg,
+                    self.theme.dialog.fg,
+                );
+            }
+            Animation::DeathFlash => {
+                // Blink every crashed snake in the defeat colours
+                if (elapsed.as_millis() / FLASH_INTERVAL.as_millis()) & 1 == 0 {
+                    let crashed = game_state.players().iter().filter(|player| !player.is_alive());
+                    for point in crashed.flat_map(|player| player.snake().body()) {
+                        let ch = self.buffer_char(point);
+                        let cell = Cell::new(ch, self.theme.defeat.fg, self.theme.defeat.bg);
+                        self.display_manager.draw_cell(&mut self.buffer, point, cell);
+                    }
+                }
+            }
+        }
+    }
+
+    // Glyph already drawn at a board position
+    fn buffer_char(&self, point: &Point) -> char {
+        let (x, y) = self.display_manager.screen_position(point);
+        self.buffer.get(x, y).map_or(' ', |cell| cell.ch)
+    }
+
+    fn draw_side_panel(&mut self, game_state: &GameState) {
+        let Some(width) = self.side_panel_width() else {
+            return;
+        };
+
+        let (origin_x, origin_y) = self.display_manager.origin();
+        let x = origin_x + self.dimensions.0 + SIDE_PANEL_GAP;
+        let bar_color = self.theme.snake.fg;
+
+        // Rows past the bottom of the board are dropped
+        let lines = side_panel_lines(game_state, self.best_score, width);
+        for (row, line) in lines.iter().take(usize::from(self.display_manager.rows())).enumerate() {
+            let y = origin_y + row as u16;
+            for (i, ch) in line.chars().enumerate() {
+                let fg = if ch == '=' { bar_color } else { self.theme.status.fg };
+                self.buffer.set(x + i as u16, y, Cell::new(ch, fg, Color::Reset));
+            }
+        }
+    }
+
+    fn draw_too_small(&mut self) {
+        self.buffer.clear();
+        let lines = [
+            "Terminal too small".to_string(),
+            format!("Need {}x{}", self.dimensions.0, self.display_manager.rows() + 1),
+            format!("Have {}x{}", self.terminal.0, self.terminal.1),
+        ];
+        for (y, line) in lines.iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                self.buffer.set(x as u16, y as u16, Cell::new(ch, Color::White, Color::Reset));
+            }
+        }
+    }
+
+    fn draw_borders(&mut self) {
         for y in 0..BORDER_THICKNESS {
             for x in 0..self.dimensions.0 {
-                self.display_manager.draw_char(
-                    &mut self.stdout,
+                self.display_manager.draw_cell(
+                    &mut self.buffer,
                     &Point::new(x, y),
-                    '█',
-                    Color::Blue,
-                    Color::Blue
-                )?;
-                self.display_manager.draw_char(
-                    &mut self.stdout,
+                    self.theme.wall
+                );
+                self.display_manager.draw_cell(
+                    &mut self.buffer,
                     &Point::new(x, self.dimensions.1 - 1 - y),
-                    '█',
-                    Color::Blue,
-                    Color::Blue
-                )?;
+                    self.theme.wall
+                );
             }
         }
 
         for x in 0..BORDER_THICKNESS {
             for y in 0..self.dimensions.1 {
-                self.display_manager.draw_char(
-                    &mut self.stdout,
+                self.display_manager.draw_cell(
+                    &mut self.buffer,
                     &Point::new(x, y),
-                    '█',
-                    Color::Blue,
-                    Color::Blue
-                )?;
-                self.display_manager.draw_char(
-                    &mut self.stdout,
+                    self.theme.wall
+                );
+                self.display_manager.draw_cell(
+                    &mut self.buffer,
                     &Point::new(self.dimensions.0 - 1 - x, y),
-                    '█',
-                    Color::Blue,
-                    Color::Blue
-                )?;
+                    self.theme.wall
+                );
+            }
+        }
+    }
+
+    fn draw_snakes(&mut self, game_state: &GameState) {
+        // Crashed snakes leave the board, but stay visible under the final result
+        let game_over = matches!(game_state.game_state(), GameStateEnum::GameOver(_));
+
+        for (player, state) in game_state.players().iter().enumerate() {
+            if !state.is_alive() && !game_over {
+                continue;
+            }
+
+            let body = state.snake().body();
+            let roles = segment_roles(body, state.snake().direction());
+            for (i, (point, role)) in body.iter().zip(roles).enumerate() {
+                let mut cell = self.theme.snake;
+                cell.ch = self.theme.snake_glyphs.glyph(role);
+                cell.fg = self.theme.player_color(player, body.len() - 1 - i, body.len());
+                self.display_manager.draw_cell(&mut self.buffer, point, cell);
             }
         }
-        Ok(())
     }
 
-    fn draw_obstacles(&mut self, obstacles: &[Obstacle]) -> Result<()> {
+    fn draw_obstacles(&mut self, obstacles: &[Obstacle]) {
         for obstacle in obstacles {
             for point in &obstacle.blocks {
-                self.display_manager.draw_char(
-                    &mut self.stdout,
+                self.display_manager.draw_cell(
+                    &mut self.buffer,
                     point,
-                    '█',
-                    Color::DarkGrey,
-                    Color::DarkGrey
-                )?;
+                    self.theme.obstacle
+                );
             }
         }
-        Ok(())
     }
 
-    fn draw_status(&mut self, game_state: &GameState) -> Result<()> {
-        let next_score = game_state.score_needed_for_next()
-            .map(|s| format!("/{}", s))
-            .unwrap_or_else(|| "".to_string());
-
-        let stats_text = format!(
-            " Level: {}/{} | Score: {}{} | Speed: {} ", 
-            game_state.current_level(),
-            game_state.max_levels(),
-            game_state.score(),
-            next_score,
-            game_state.speed_level(),
-        );
+    fn draw_pickups(&mut self, game_state: &GameState) {
+        let numbered = matches!(game_state.objective(), Objective::CollectInOrder(_));
+        let first_number = game_state.objective_progress().current + 1;
+
+        for (i, point) in game_state.pickups().iter().enumerate() {
+            let mut cell = self.theme.pickup;
+            if numbered {
+                cell.ch = char::from_digit((first_number + i as u32) % 10, 10).unwrap_or('?');
+            }
+            self.display_manager.draw_cell(&mut self.buffer, point, cell);
+        }
+    }
+
+    fn objective_text(game_state: &GameState) -> Option<String> {
+        let progress = game_state.objective_progress();
+        let text = match game_state.objective() {
+            Objective::Score => return None,
+            Objective::ReachLength(_) => format!("Length {}/{}", progress.current, progress.target),
+            Objective::CollectInOrder(_) => format!("In order {}/{}", progress.current, progress.target),
+            Objective::Survive { .. } => format!("Survive {}/{}s", progress.current, progress.target),
+            Objective::CollectAll { .. } => format!(
+                "Collect {}/{} in {} moves",
+                progress.current,
+                progress.target,
+                progress.moves_left.unwrap_or(0)
+            ),
+        };
+        Some(text)
+    }
+
+    // "P1 5", "P2 3 x", ..., crossing out snakes that have crashed
+    fn player_scores(game_state: &GameState) -> Vec<String> {
+        game_state.players().iter().enumerate()
+            .map(|(i, player)| {
+                let mark = if player.is_alive() { "" } else { " x" };
+                format!("P{} {}{}", i + 1, player.score(), mark)
+            })
+            .collect()
+    }
+
+    fn scoreboard(game_state: &GameState) -> String {
+        Self::player_scores(game_state).join(" | ")
+    }
 
+    fn draw_status(&mut self, game_state: &GameState) {
+        let mut fields = Self::status_fields(game_state);
+        if self.hint == Hint::NoSafePath {
+            fields.insert(0, ("NO SAFE PATH".to_string(), 3));
+        }
+
+        // The bar starts two cells in
+        let width = usize::from(self.dimensions.0.saturating_sub(2));
         self.display_manager.draw_status_bar(
-            &mut self.stdout,
-            &stats_text,
-            Color::DarkBlue,
-            Color::White,
+            &mut self.buffer,
+            &fit_status(fields, width),
+            self.theme.status.bg,
+            self.theme.status.fg,
         )
     }
 
-    fn draw_transition(&mut self, game_state: &GameState) -> Result<()> {
-        self.display_manager.draw_centered_box(
-            &mut self.stdout,
+    // Status bar fields in display order, each with a priority: when the
+    // board is too narrow, the lowest go first so score and speed stay on screen
+    fn status_fields(game_state: &GameState) -> Vec<(String, u8)> {
+        if game_state.players().len() > 1 {
+            let mut fields: Vec<(String, u8)> = Self::player_scores(game_state).into_iter()
+                .map(|player| (player, 5))
+                .collect();
+            fields.push((format!("First to {}", game_state.match_target()), 1));
+            return fields;
+        }
+
+        let next_score = game_state.score_needed_for_next()
+            .map(|s| format!("/{}", s))
+            .unwrap_or_default();
+
+        let level_text = match game_state.mode() {
+            GameMode::Classic => format!("{}/{}", game_state.current_level(), game_state.max_levels()),
+            GameMode::Endless => format!("{} (endless)", game_state.current_level()),
+        };
+
+        let objective_text = match game_state.exit_gate() {
+            Some(_) => Some("EXIT OPEN - reach the gate".to_string()),
+            None => Self::objective_text(game_state),
+        };
+
+        match objective_text {
+            Some(objective) => vec![
+                (format!("Level: {}", level_text), 1),
+                (objective, 2),
+                (format!("Score: {}", game_state.score()), 5),
+                (format!("Speed: {}", game_state.speed_level()), 4),
+            ],
+            None => vec![
+                (game_state.difficulty().name().to_string(), 0),
+                (format!("Level: {}", level_text), 1),
+                (format!("Score: {}{}", game_state.score(), next_score), 5),
+                (format!("Speed: {}", game_state.speed_level()), 4),
+            ],
+        }
+    }
+
+    fn draw_toast(&mut self) {
+        if self.toast.as_ref().is_some_and(|(_, shown)| shown.elapsed() > TOAST_DURATION) {
+            self.toast = None;
+        }
+
+        if let Some((text, _)) = &self.toast {
+            let text = format!(" {} ", text);
+            let x = self.dimensions.0.saturating_sub(text.chars().count() as u16) / 2;
+            self.display_manager.draw_text(
+                &mut self.buffer,
+                &Point::new(x, 0),
+                &text,
+                self.theme.toast.fg,
+                self.theme.toast.bg,
+            );
+        }
+    }
+
+    fn draw_transition(&mut self, game_state: &GameState) {
+        let message = format!(
+            "{}\nPress {} to continue",
             game_state.transition_message(),
-            Color::DarkBlue,
-            Color::White,
+            self.bindings.label(Action::Confirm),
+        );
+        self.display_manager.draw_centered_box(
+            &mut self.buffer,
+            &message,
+            self.theme.dialog.bg,
+            self.theme.dialog.fg,
         )
     }
 
-    fn draw_game_over(&mut self, game_state: &GameState, reason: GameEndReason) -> Result<()> {
+    fn draw_game_over(&mut self, game_state: &GameState, reason: GameEndReason) {
         let message = match reason {
             GameEndReason::Victory => format!(
-                "VICTORY!\nFinal Score: {}\nAll {} Levels Complete!\nPress 'q' to quit",
+                "VICTORY!\nFinal Score: {}\nAll {} Levels Complete!",
                 game_state.score(),
                 game_state.max_levels()
             ),
+            GameEndReason::Collision if game_state.mode() == GameMode::Endless => format!(
+                "GAME OVER!\nFinal Score: {}\nReached Level {}",
+                game_state.score(),
+                game_state.current_level()
+            ),
             GameEndReason::Collision => format!(
-                "GAME OVER!\nFinal Score: {}\nLevel {} of {}\nPress 'q' to quit",
+                "GAME OVER!\nFinal Score: {}\nLevel {} of {}",
                 game_state.score(),
                 game_state.current_level(),
                 game_state.max_levels()
             ),
+            GameEndReason::OutOfMoves => format!(
+                "OUT OF MOVES!\nFinal Score: {}\nLevel {} of {}",
+                game_state.score(),
+                game_state.current_level(),
+                game_state.max_levels()
+            ),
+            GameEndReason::BoardFull => format!(
+                "BOARD FILLED!\nFinal Score: {}\nLength {}",
+                game_state.score(),
+                game_state.snake().body().len()
+            ),
+            GameEndReason::Winner(player) => format!(
+                "PLAYER {} WINS!\n{}",
+                player + 1,
+                Self::scoreboard(game_state)
+            ),
+            GameEndReason::Draw => format!("DRAW!\n{}", Self::scoreboard(game_state)),
+        };
+
+        let quit = self.bindings.label(Action::Quit);
+        let message = match self.best_score {
+            Some(best) => format!(
+                "{}\n{} Best: {}\nPress {} to quit",
+                message,
+                game_state.difficulty().name(),
+                best,
+                quit
+            ),
+            None => format!("{}\nPress {} to quit", message, quit),
         };
 
-        let bg_color = match reason {
-            GameEndReason::Victory => Color::Green,
-            GameEndReason::Collision => Color::Red,
+        let style = match reason {
+            GameEndReason::Victory | GameEndReason::BoardFull | GameEndReason::Winner(_) => self.theme.victory,
+            GameEndReason::Collision | GameEndReason::OutOfMoves | GameEndReason::Draw => self.theme.defeat,
         };
 
         self.display_manager.draw_centered_box(
-            &mut self.stdout,
+            &mut self.buffer,
             &message,
-            bg_color,
-            Color::White,
+            style.bg,
+            style.fg,
         )
     }
 }
 
-impl Drop for Renderer {
+impl<B: RenderBackend> Drop for Renderer<B> {
     fn drop(&mut self) {
         let _ = self.cleanup();
     }
-}
\ No newline at end of file
+}
+
+// " a | b | c ", leaving out the lowest-priority fields (the later one on a tie) until it fits
+fn fit_status(mut fields: Vec<(String, u8)>, width: usize) -> String {
+    loop {
+        let text = format!(" {} ", fields.iter().map(|(text, _)| text.as_str()).collect::<Vec<_>>().join(" | "));
+        if text.chars().count() <= width || fields.len() <= 1 {
+            return text;
+        }
+        let lowest = fields.iter().enumerate()
+            .min_by_key(|(i, (_, priority))| (*priority, std::cmp::Reverse(*i)))
+            .map_or(0, |(i, _)| i);
+        fields.remove(lowest);
+    }
+}
@@ -0,0 +1,49 @@
+// src/ui/sprites.rs
+use std::collections::VecDeque;
+use crate::entities::{Direction, Point};
+
+// What part of the snake a segment is, with the directions needed to pick a glyph.
+// Directions point from the segment towards its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentRole {
+    Head(Direction),
+    Straight { horizontal: bool },
+    Corner(Direction, Direction),
+    Tail(Direction),
+}
+
+// Direction of the step from `from` to `to`. Steps longer than one cell can
+// only come from wrapping around the board, so they point the other way.
+pub fn direction_between(from: &Point, to: &Point) -> Option<Direction> {
+    let dx = i32::from(to.x) - i32::from(from.x);
+    let dy = i32::from(to.y) - i32::from(from.y);
+
+    match (dx.signum(), dy.signum(), dx.abs() + dy.abs() > 1) {
+        (1, 0, false) | (-1, 0, true) => Some(Direction::Right),
+        (-1, 0, false) | (1, 0, true) => Some(Direction::Left),
+        (0, 1, false) | (0, -1, true) => Some(Direction::Down),
+        (0, -1, false) | (0, 1, true) => Some(Direction::Up),
+        _ => None,
+    }
+}
+
+// Roles in body order, tail first and head last
+pub fn segment_roles(body: &VecDeque<Point>, heading: Direction) -> Vec<SegmentRole> {
+    let len = body.len();
+
+    (0..len).map(|i| {
+        let towards_tail = (i > 0).then(|| direction_between(&body[i], &body[i - 1])).flatten();
+        let towards_head = (i + 1 < len).then(|| direction_between(&body[i], &body[i + 1])).flatten();
+
+        match (towards_tail, towards_head) {
+            // The head faces away from the neck
+            (Some(neck), None) => SegmentRole::Head(neck.opposite()),
+            (None, None) => SegmentRole::Head(heading),
+            (None, Some(next)) => SegmentRole::Tail(next),
+            (Some(a), Some(b)) if a == b.opposite() => SegmentRole::Straight {
+                horizontal: matches!(a, Direction::Left | Direction::Right),
+            },
+            (Some(a), Some(b)) => SegmentRole::Corner(a, b),
+        }
+    }).collect()
+}
@@ -0,0 +1,24 @@
+// src/ui/text.rs
+use crate::{
+    core::GameState,
+    ui::{MemoryBackend, Renderer, Theme},
+};
+
+// The frame as plain lines of text, for golden files, bug reports and logs.
+// Uses the ASCII theme so it reads the same in any editor or log viewer.
+pub fn render_text(game_state: &GameState) -> String {
+    render_text_with_theme(game_state, Theme::ascii())
+}
+
+pub fn render_text_with_theme(game_state: &GameState, theme: Theme) -> String {
+    let (width, height) = game_state.dimensions();
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(width, height + 1), width, height);
+    renderer.set_theme(theme);
+
+    // Drawing into memory can't fail
+    let _ = renderer.render(game_state);
+
+    renderer.backend().lines().iter()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect()
+}
@@ -0,0 +1,302 @@
+// src/ui/theme.rs
+use crossterm::style::Color;
+use crate::{
+    utils::{Result, GameError},
+    config::{ThemeSettings, StyleOverride},
+    entities::Direction,
+    ui::{Cell, SegmentRole},
+};
+
+// Glyphs for each snake segment role, indexed by direction in the order
+// up, down, left, right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnakeGlyphs {
+    pub head: [char; 4],      // Facing
+    pub straight: [char; 2],  // Horizontal, vertical
+    pub corners: [char; 4],   // Joining up+right, up+left, down+right, down+left
+    pub tail: [char; 4],      // Body continues towards
+}
+
+impl SnakeGlyphs {
+    pub fn box_drawing() -> Self {
+        Self {
+            head: ['▲', '▼', '◀', '▶'],
+            straight: ['━', '┃'],
+            corners: ['┗', '┛', '┏', '┓'],
+            tail: ['╹', '╻', '╸', '╺'],
+        }
+    }
+
+    pub fn ascii() -> Self {
+        Self {
+            head: ['^', 'v', '<', '>'],
+            straight: ['-', '|'],
+            corners: ['+'; 4],
+            tail: ['.'; 4],
+        }
+    }
+
+    pub fn uniform(glyph: char) -> Self {
+        Self {
+            head: [glyph; 4],
+            straight: [glyph; 2],
+            corners: [glyph; 4],
+            tail: [glyph; 4],
+        }
+    }
+
+    pub fn glyph(&self, role: SegmentRole) -> char {
+        match role {
+            SegmentRole::Head(facing) => self.head[direction_index(facing)],
+            SegmentRole::Straight { horizontal } => self.straight[if horizontal { 0 } else { 1 }],
+            SegmentRole::Corner(a, b) => {
+                let vertical = if matches!(a, Direction::Up | Direction::Down) { a } else { b };
+                let horizontal = if vertical == a { b } else { a };
+                let index = match (vertical, horizontal) {
+                    (Direction::Up, Direction::Right) => 0,
+                    (Direction::Up, _) => 1,
+                    (_, Direction::Right) => 2,
+                    _ => 3,
+                };
+                self.corners[index]
+            }
+            SegmentRole::Tail(towards) => self.tail[direction_index(towards)],
+        }
+    }
+}
+
+fn direction_index(direction: Direction) -> usize {
+    match direction {
+        Direction::Up => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Right => 3,
+    }
+}
+
+// Glyph and colours for every element the renderer draws
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub snake: Cell,
+    pub snake_glyphs: SnakeGlyphs,
+    pub snake_gradient: Option<(Color, Color)>,  // Head to tail, RGB colours only
+    pub rival_colors: [Color; 3],                // Snakes of players 2 to 4
+    pub wall: Cell,
+    pub obstacle: Cell,
+    pub food: Cell,
+    pub pickup: Cell,
+    pub gate: Cell,
+    pub status: Cell,
+    pub dialog: Cell,
+    pub victory: Cell,
+    pub defeat: Cell,
+    pub toast: Cell,
+    pub hint: Cell,  // Dots along the suggested path
+}
+
+pub const THEME_NAMES: [&str; 4] = ["default", "high-contrast", "colour-blind", "ascii"];
+
+impl Theme {
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "default" => Some(Self::default_theme()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "colour-blind" | "color-blind" => Some(Self::colour_blind()),
+            "ascii" => Some(Self::ascii()),
+            _ => None,
+        }
+    }
+
+    pub fn default_theme() -> Self {
+        Self {
+            name: "default".to_string(),
+            snake: Cell::new('█', Color::Green, Color::Reset),
+            snake_glyphs: SnakeGlyphs::box_drawing(),
+            snake_gradient: None,
+            rival_colors: [Color::Magenta, Color::Cyan, Color::Yellow],
+            wall: Cell::new('█', Color::Blue, Color::Blue),
+            obstacle: Cell::new('█', Color::DarkGrey, Color::DarkGrey),
+            food: Cell::new('●', Color::Red, Color::Reset),
+            pickup: Cell::new('◆', Color::Yellow, Color::Reset),
+            gate: Cell::new('▒', Color::White, Color::Magenta),
+            status: Cell::new(' ', Color::White, Color::DarkBlue),
+            dialog: Cell::new(' ', Color::White, Color::DarkBlue),
+            victory: Cell::new(' ', Color::White, Color::Green),
+            defeat: Cell::new(' ', Color::White, Color::Red),
+            toast: Cell::new(' ', Color::Black, Color::Yellow),
+            hint: Cell::new('·', Color::DarkGrey, Color::Reset),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast".to_string(),
+            snake: Cell::new('█', Color::Yellow, Color::Black),
+            snake_glyphs: SnakeGlyphs::box_drawing(),
+            snake_gradient: None,
+            rival_colors: [Color::Cyan, Color::Magenta, Color::White],
+            wall: Cell::new('█', Color::White, Color::White),
+            obstacle: Cell::new('▓', Color::White, Color::Black),
+            food: Cell::new('●', Color::Red, Color::Black),
+            pickup: Cell::new('◆', Color::Cyan, Color::Black),
+            gate: Cell::new('▒', Color::Black, Color::White),
+            status: Cell::new(' ', Color::Black, Color::White),
+            dialog: Cell::new(' ', Color::Black, Color::White),
+            victory: Cell::new(' ', Color::Black, Color::Yellow),
+            defeat: Cell::new(' ', Color::White, Color::Red),
+            toast: Cell::new(' ', Color::Black, Color::Yellow),
+            hint: Cell::new('·', Color::Grey, Color::Black),
+        }
+    }
+
+    // Okabe-Ito palette: never relies on telling red from green
+    pub fn colour_blind() -> Self {
+        let blue = Color::Rgb { r: 0, g: 114, b: 178 };
+        let orange = Color::Rgb { r: 230, g: 159, b: 0 };
+        let sky = Color::Rgb { r: 86, g: 180, b: 233 };
+        let yellow = Color::Rgb { r: 240, g: 228, b: 66 };
+        let purple = Color::Rgb { r: 204, g: 121, b: 167 };
+        let vermillion = Color::Rgb { r: 213, g: 94, b: 0 };
+
+        Self {
+            name: "colour-blind".to_string(),
+            snake: Cell::new('█', sky, Color::Reset),
+            snake_glyphs: SnakeGlyphs::box_drawing(),
+            snake_gradient: None,
+            rival_colors: [purple, vermillion, Color::White],
+            wall: Cell::new('█', blue, blue),
+            obstacle: Cell::new('█', Color::Grey, Color::Grey),
+            food: Cell::new('●', orange, Color::Reset),
+            pickup: Cell::new('◆', yellow, Color::Reset),
+            gate: Cell::new('▒', Color::White, purple),
+            status: Cell::new(' ', Color::White, blue),
+            dialog: Cell::new(' ', Color::White, blue),
+            victory: Cell::new(' ', Color::Black, sky),
+            defeat: Cell::new(' ', Color::White, vermillion),
+            toast: Cell::new(' ', Color::Black, yellow),
+            hint: Cell::new('·', Color::DarkGrey, Color::Reset),
+        }
+    }
+
+    // Only 7-bit characters, for fonts without block glyphs
+    pub fn ascii() -> Self {
+        Self {
+            name: "ascii".to_string(),
+            snake: Cell::new('o', Color::Green, Color::Reset),
+            snake_glyphs: SnakeGlyphs::ascii(),
+            snake_gradient: None,
+            rival_colors: [Color::Magenta, Color::Cyan, Color::Yellow],
+            wall: Cell::new('#', Color::Blue, Color::Reset),
+            obstacle: Cell::new('X', Color::DarkGrey, Color::Reset),
+            food: Cell::new('*', Color::Red, Color::Reset),
+            pickup: Cell::new('$', Color::Yellow, Color::Reset),
+            gate: Cell::new('E', Color::Magenta, Color::Reset),
+            status: Cell::new(' ', Color::White, Color::DarkBlue),
+            dialog: Cell::new(' ', Color::White, Color::DarkBlue),
+            victory: Cell::new(' ', Color::White, Color::Green),
+            defeat: Cell::new(' ', Color::White, Color::Red),
+            toast: Cell::new(' ', Color::Black, Color::Yellow),
+            hint: Cell::new(':', Color::DarkGrey, Color::Reset),
+        }
+    }
+
+    // Starts from the named built-in theme and applies per-element overrides
+    pub fn from_settings(settings: &ThemeSettings) -> Result<Self> {
+        let name = settings.name.as_deref().unwrap_or("default");
+        let mut theme = Self::by_name(name)
+            .ok_or_else(|| invalid(format!("unknown theme '{}', expected one of {:?}", name, THEME_NAMES)))?;
+
+        let overrides = [
+            (&settings.snake, &mut theme.snake),
+            (&settings.wall, &mut theme.wall),
+            (&settings.obstacle, &mut theme.obstacle),
+            (&settings.food, &mut theme.food),
+            (&settings.pickup, &mut theme.pickup),
+            (&settings.gate, &mut theme.gate),
+            (&settings.status, &mut theme.status),
+            (&settings.dialog, &mut theme.dialog),
+            (&settings.hint, &mut theme.hint),
+        ];
+        for (style, cell) in overrides {
+            if let Some(style) = style {
+                apply_override(style, cell)?;
+            }
+        }
+
+        // A custom snake glyph replaces the whole segment set
+        if let Some(glyph) = settings.snake.as_ref().and_then(|style| style.glyph) {
+            theme.snake_glyphs = SnakeGlyphs::uniform(glyph);
+        }
+
+        if let Some(colors) = &settings.snake_gradient {
+            match colors.as_slice() {
+                [head, tail] => theme.snake_gradient = Some((parse_color(head)?, parse_color(tail)?)),
+                _ => return Err(invalid("snake_gradient needs exactly two colours".to_string())),
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::default_theme()
+    }
+}
+
+impl Theme {
+    // Colour of the segment `index` places behind the head, out of `len`
+    pub fn snake_color(&self, index: usize, len: usize) -> Color {
+        match self.snake_gradient {
+            Some((Color::Rgb { r: r1, g: g1, b: b1 }, Color::Rgb { r: r2, g: g2, b: b2 })) if len > 1 => {
+                let t = index as f32 / (len - 1) as f32;
+                let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+                Color::Rgb { r: mix(r1, r2), g: mix(g1, g2), b: mix(b1, b2) }
+            }
+            _ => self.snake.fg,
+        }
+    }
+
+    // Player 1 keeps the theme's snake colours, the others get a flat rival colour
+    pub fn player_color(&self, player: usize, index: usize, len: usize) -> Color {
+        match player.checked_sub(1) {
+            None => self.snake_color(index, len),
+            Some(rival) => self.rival_colors[rival % self.rival_colors.len()],
+        }
+    }
+}
+
+fn apply_override(style: &StyleOverride, cell: &mut Cell) -> Result<()> {
+    if let Some(glyph) = style.glyph {
+        cell.ch = glyph;
+    }
+    if let Some(fg) = &style.fg {
+        cell.fg = parse_color(fg)?;
+    }
+    if let Some(bg) = &style.bg {
+        cell.bg = parse_color(bg)?;
+    }
+    Ok(())
+}
+
+// Accepts crossterm colour names, "reset" and "#rrggbb"
+pub fn parse_color(text: &str) -> Result<Color> {
+    if text.eq_ignore_ascii_case("reset") {
+        return Ok(Color::Reset);
+    }
+
+    if let Some(hex) = text.strip_prefix('#') {
+        let channel = |i: usize| hex.get(i..i + 2).and_then(|c| u8::from_str_radix(c, 16).ok());
+        if let (6, Some(r), Some(g), Some(b)) = (hex.len(), channel(0), channel(2), channel(4)) {
+            return Ok(Color::Rgb { r, g, b });
+        }
+    }
+
+    Color::try_from(text).map_err(|_| invalid(format!("unknown colour '{}'", text)))
+}
+
+fn invalid(message: String) -> GameError {
+    GameError::Config(config::ConfigError::Message(message))
+}
@@ -8,6 +8,9 @@ pub enum GameError {
     #[error("Configuration error: {0}")]
     Config(#[from] config::ConfigError),
 
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
     #[error("Game state error: {0}")]
     GameState(String),
 
@@ -0,0 +1,82 @@
+// tests/achievement_tests.rs
+use snake_game::config::MAX_PENDING_EVENTS;
+use snake_game::core::{AchievementTracker, GameState};
+use snake_game::entities::Direction;
+use snake_game::gameplay::GameEvent;
+
+fn food_at(at_ms: u64) -> GameEvent {
+    GameEvent::FoodEaten { at_ms, length: 4, score: 1 }
+}
+
+#[test]
+fn test_feeding_frenzy_needs_five_in_ten_seconds() {
+    let mut tracker = AchievementTracker::new();
+
+    // Spread out over more than ten seconds - no unlock
+    for i in 0..5 {
+        tracker.process(&food_at(i * 3_000));
+    }
+    assert!(!tracker.is_unlocked("feeding_frenzy"));
+
+    for i in 0..5 {
+        tracker.process(&food_at(20_000 + i * 1_000));
+    }
+    assert!(tracker.is_unlocked("feeding_frenzy"), "Five food within ten seconds should unlock");
+}
+
+#[test]
+fn test_steady_hands_counts_turns_per_level() {
+    let mut tracker = AchievementTracker::new();
+
+    for _ in 0..60 {
+        tracker.process(&GameEvent::Turned { at_ms: 0 });
+    }
+    tracker.process(&GameEvent::LevelComplete { level: 2, at_ms: 0 });
+
+    // Turn count starts over for level 3
+    for _ in 0..10 {
+        tracker.process(&GameEvent::Turned { at_ms: 0 });
+    }
+    let unlocked = tracker.process(&GameEvent::LevelComplete { level: 3, at_ms: 0 });
+    assert!(unlocked.iter().any(|a| a.id == "steady_hands"));
+}
+
+#[test]
+fn test_unlocks_are_reported_once_and_persist() {
+    let mut tracker = AchievementTracker::new();
+    let first = tracker.process(&food_at(0));
+    assert!(first.iter().any(|a| a.id == "first_bite"));
+    assert!(tracker.process(&food_at(1)).is_empty(), "Unlocks should only be reported once");
+
+    let path = std::env::temp_dir().join("snake_achievements_round_trip.json");
+    tracker.save(&path).unwrap();
+    let loaded = AchievementTracker::load(&path).unwrap();
+    assert!(loaded.is_unlocked("first_bite"));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_game_state_reports_turns() {
+    let mut game = GameState::new();
+    game.change_direction(Direction::Up);
+    game.change_direction(Direction::Down); // Rejected, not a turn
+
+    let events = game.drain_events();
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], GameEvent::Turned { .. }));
+    assert!(game.drain_events().is_empty());
+}
+
+#[test]
+fn test_undrained_events_are_capped() {
+    let mut game = GameState::new();
+    for _ in 0..MAX_PENDING_EVENTS {
+        game.change_direction(Direction::Up);
+        game.change_direction(Direction::Right);
+    }
+    game.update().unwrap();
+
+    let events = game.drain_events();
+    assert_eq!(events.len(), MAX_PENDING_EVENTS);
+    assert!(events.iter().all(|event| matches!(event, GameEvent::Turned { .. })));
+}
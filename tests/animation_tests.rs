@@ -0,0 +1,57 @@
+// tests/animation_tests.rs
+use std::time::{Duration, Instant};
+use snake_game::config::Config;
+use snake_game::core::GameState;
+use snake_game::ui::{countdown_label, Animation, Animator, MemoryBackend, Renderer};
+
+#[test]
+fn test_countdown_labels() {
+    assert_eq!(countdown_label(Duration::ZERO), "3");
+    assert_eq!(countdown_label(Duration::from_millis(700)), "2");
+    assert_eq!(countdown_label(Duration::from_millis(1200)), "1");
+    assert_eq!(countdown_label(Duration::from_millis(1900)), "GO!");
+    assert_eq!(countdown_label(Duration::from_secs(60)), "GO!");
+}
+
+#[test]
+fn test_animations_play_in_sequence() {
+    let start = Instant::now();
+    let mut animator = Animator::new();
+    animator.play(Animation::Wipe, start);
+    animator.play(Animation::Countdown, start);
+
+    let wipe = Animation::Wipe.duration();
+    assert_eq!(animator.current(start), Some((Animation::Wipe, Duration::ZERO)));
+    assert_eq!(
+        animator.current(start + wipe + Duration::from_millis(100)),
+        Some((Animation::Countdown, Duration::from_millis(100)))
+    );
+    assert!(animator.holds_play(start + wipe));
+    assert!(animator.is_idle(start + wipe + Animation::Countdown.duration()));
+}
+
+#[test]
+fn test_death_flash_does_not_hold_play() {
+    let start = Instant::now();
+    let mut animator = Animator::new();
+    animator.play(Animation::DeathFlash, start);
+
+    assert!(!animator.holds_play(start));
+    assert!(!animator.is_idle(start));
+    animator.clear();
+    assert!(animator.is_idle(start));
+}
+
+#[test]
+fn test_countdown_is_drawn_over_board() {
+    // Seeded so the food can't land on the countdown's row
+    let mut config = Config::new();
+    config.seed = Some(1);
+    let game = GameState::with_config(config);
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(50, 26), 50, 25);
+    renderer.play(Animation::Countdown);
+    renderer.render(&game).unwrap();
+
+    assert!(renderer.holds_play());
+    assert!(renderer.backend().lines().iter().any(|line| line.trim_matches(|c| c == ' ' || c == '█') == "3"));
+}
@@ -0,0 +1,107 @@
+// tests/backend_tests.rs
+use snake_game::core::GameState;
+use snake_game::config::Config;
+use snake_game::gameplay::{GameState as GameStateEnum, Objective};
+use std::{cell::Cell, rc::Rc};
+use snake_game::ui::{FrameBuffer, MemoryBackend, RenderBackend, Renderer, Theme};
+use snake_game::utils::Result;
+use crossterm::style::Color;
+
+fn small_config() -> Config {
+    let mut config = Config::new();
+    config.width = 30;
+    config.height = 15;
+    config
+}
+
+#[test]
+fn test_memory_backend_captures_frame() {
+    let config = small_config();
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(30, 16), config.width, config.height);
+    renderer.set_theme(Theme::ascii());
+    let game = GameState::with_config(config);
+
+    renderer.render(&game).unwrap();
+    let screen = renderer.backend();
+
+    assert_eq!(screen.frames(), 1);
+    assert_eq!(screen.line(0), "#".repeat(30));
+    assert!(screen.line(15).contains("Score: 0/5"), "Status bar should be on the last row: {:?}", screen.line(15));
+
+    let (fx, fy) = screen.find('*').expect("Food should be drawn");
+    let food = game.food().position();
+    assert_eq!((fx, fy), (food.x, food.y));
+    assert_eq!(screen.cell(fx, fy).unwrap().fg, Theme::ascii().food.fg);
+}
+
+#[test]
+fn test_memory_backend_follows_game_updates() {
+    let config = small_config();
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(30, 16), config.width, config.height);
+    let mut game = GameState::with_config(config);
+
+    renderer.render(&game).unwrap();
+    let head = *game.snake().head().unwrap();
+    game.update().unwrap();
+    renderer.render(&game).unwrap();
+
+    let screen = renderer.backend();
+    let new_head = game.snake().head().unwrap();
+    assert_eq!(screen.cell(new_head.x, new_head.y).unwrap().ch, '▶');
+    assert_ne!(screen.cell(head.x, head.y).unwrap().ch, '▶');
+    assert_eq!(screen.cell(0, 0).unwrap().bg, Color::Blue);
+}
+
+#[test]
+fn test_board_is_centered_in_larger_screen() {
+    let config = small_config();
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(40, 20), config.width, config.height);
+    renderer.set_theme(Theme::ascii());
+    renderer.render(&GameState::with_config(config)).unwrap();
+
+    let screen = renderer.backend();
+    assert_eq!(screen.find('#'), Some((5, 2)));
+}
+
+#[test]
+fn test_toast_shows_between_levels() {
+    let mut config = small_config();
+    config.level_objectives = vec![Objective::Survive { seconds: 1 }, Objective::Score];
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(30, 16), config.width, config.height);
+    let mut game = GameState::with_config(config);
+    while game.game_state() == GameStateEnum::Playing {
+        game.update().unwrap();
+    }
+    assert_eq!(game.game_state(), GameStateEnum::LevelTransition);
+
+    renderer.show_toast("Unlocked".to_string());
+    renderer.render(&game).unwrap();
+    assert!(renderer.backend().line(0).contains("Unlocked"), "{:?}", renderer.backend().line(0));
+}
+
+// Counts how often the terminal would be put back
+struct TeardownCounter(Rc<Cell<u32>>);
+
+impl RenderBackend for TeardownCounter {
+    fn size(&self) -> Option<(u16, u16)> { None }
+    fn init(&mut self) -> Result<()> { Ok(()) }
+    fn cleanup(&mut self) -> Result<()> {
+        self.0.set(self.0.get() + 1);
+        Ok(())
+    }
+    fn draw(&mut self, _buffer: &mut FrameBuffer) -> Result<()> { Ok(()) }
+}
+
+#[test]
+fn test_terminal_is_restored_once() {
+    let cleanups = Rc::new(Cell::new(0));
+    let mut renderer = Renderer::with_backend(TeardownCounter(cleanups.clone()), 30, 15);
+    renderer.init().unwrap();
+    renderer.cleanup().unwrap();
+    drop(renderer);
+    assert_eq!(cleanups.get(), 1, "Dropping after cleanup should not restore again");
+
+    // Never set up, so there's nothing to restore
+    drop(Renderer::with_backend(TeardownCounter(cleanups.clone()), 30, 15));
+    assert_eq!(cleanups.get(), 1);
+}
@@ -0,0 +1,55 @@
+// tests/bench_tests.rs
+use std::time::Duration;
+use snake_game::bot::{bench_bot, format_table, play_game, summarize, BenchOptions, GameOutcome, GreedyBot};
+use snake_game::config::Config;
+use snake_game::gameplay::GameMode;
+
+fn outcome(seed: u64, score: u32, ending: &str) -> GameOutcome {
+    GameOutcome { seed, score, levels_cleared: score / 5, ticks: 100, ending: ending.to_string() }
+}
+
+#[test]
+fn test_seeded_games_replay_exactly() {
+    let mut config = Config::new();
+    config.mode = GameMode::Endless;
+    let first = play_game(&mut GreedyBot::new(), config, 9, 3000).unwrap();
+
+    let mut config = Config::new();
+    config.mode = GameMode::Endless;
+    let second = play_game(&mut GreedyBot::new(), config, 9, 3000).unwrap();
+
+    assert_eq!(first, second);
+    assert!(first.ticks > 0 && first.ticks <= 3000);
+}
+
+#[test]
+fn test_summary_statistics() {
+    let outcomes = [outcome(0, 10, "wall"), outcome(1, 2, "self"), outcome(2, 7, "wall"), outcome(3, 5, "tick limit")];
+    let report = summarize("test", &outcomes, Duration::from_secs(2));
+
+    assert_eq!(report.games, 4);
+    assert_eq!(report.mean_score, 6.0);
+    assert_eq!(report.median_score, 6.0);
+    assert_eq!(report.mean_levels_cleared, 1.0);
+    assert_eq!(report.endings["wall"], 2);
+    assert_eq!(report.ticks_per_second, 200.0);
+
+    let table = format_table(&[report]);
+    assert!(table.lines().nth(1).unwrap().contains("self 1, tick limit 1, wall 2"), "{}", table);
+}
+
+#[test]
+fn test_threads_do_not_change_results() {
+    let options = BenchOptions { games: 6, max_ticks: 2000, ..Default::default() };
+    let single = bench_bot("greedy", &options).unwrap();
+    let threaded = bench_bot("greedy", &BenchOptions { threads: 3, ..options }).unwrap();
+
+    assert_eq!(single.mean_score, threaded.mean_score);
+    assert_eq!(single.endings, threaded.endings);
+    assert_eq!(single.ticks, threaded.ticks);
+}
+
+#[test]
+fn test_unknown_bot_is_an_error() {
+    assert!(bench_bot("nobody", &BenchOptions::default()).is_err());
+}
@@ -0,0 +1,69 @@
+// tests/binding_tests.rs
+use snake_game::config::KeySettings;
+use snake_game::ui::{parse_key, Action, Context, KeyBindings, BINDING_PRESETS};
+use crossterm::event::KeyCode;
+
+#[test]
+fn test_presets_have_no_conflicts() {
+    for name in BINDING_PRESETS {
+        let bindings = KeyBindings::by_name(name).unwrap_or_else(|| panic!("Missing preset {}", name));
+        bindings.check_conflicts().unwrap_or_else(|e| panic!("{}: {}", name, e));
+    }
+}
+
+#[test]
+fn test_lookup_by_context() {
+    let bindings = KeyBindings::default();
+    assert_eq!(bindings.action(KeyCode::Char('W'), Context::Game), Some(Action::MoveUp));
+    assert_eq!(bindings.action(KeyCode::Up, Context::Menu), Some(Action::MoveUp));
+    assert_eq!(bindings.action(KeyCode::Char('a'), Context::Game), Some(Action::MoveLeft));
+    assert_eq!(bindings.action(KeyCode::Char('a'), Context::Menu), Some(Action::Achievements));
+
+    let azerty = KeyBindings::by_name("azerty").unwrap();
+    assert_eq!(azerty.action(KeyCode::Char('q'), Context::Game), Some(Action::MoveLeft));
+    assert_eq!(azerty.action(KeyCode::Esc, Context::Game), Some(Action::Quit));
+}
+
+#[test]
+fn test_overrides_and_conflicts() {
+    let settings = KeySettings {
+        preset: Some("vim".to_string()),
+        pause: Some(vec!["space".to_string(), "F5".to_string()]),
+        confirm: Some(vec!["enter".to_string()]),
+        ..KeySettings::default()
+    };
+    let bindings = KeyBindings::from_settings(&settings).unwrap();
+    assert_eq!(bindings.keys(Action::Pause), &[KeyCode::Char(' '), KeyCode::F(5)]);
+    assert_eq!(bindings.action(KeyCode::Char('k'), Context::Game), Some(Action::MoveUp));
+
+    // Space is still the default confirm key, and both are used in game
+    let clashing = KeySettings { pause: Some(vec!["space".to_string()]), ..KeySettings::default() };
+    let error = KeyBindings::from_settings(&clashing).unwrap_err().to_string();
+    assert!(error.contains("pause") && error.contains("confirm"), "{}", error);
+}
+
+#[test]
+fn test_player_keys_clash_with_match_actions() {
+    let player_key = |key: &str| KeySettings {
+        players: Some(vec![vec!["w".into(), "s".into(), "a".into(), key.into()]]),
+        ..KeySettings::default()
+    };
+    for (key, action) in [("p", "pause"), ("space", "confirm"), ("esc", "quit"), ("?", "hint")] {
+        let error = KeyBindings::from_settings(&player_key(key)).unwrap_err().to_string();
+        assert!(error.contains("player 1") && error.contains(action), "{}: {}", key, error);
+    }
+
+    // Achievements only work in the menu, so a player can have its key
+    let mut settings = player_key("d");
+    settings.achievements = Some(vec!["d".into()]);
+    assert!(KeyBindings::from_settings(&settings).is_ok());
+}
+
+#[test]
+fn test_parse_key() {
+    assert_eq!(parse_key("Up").unwrap(), KeyCode::Up);
+    assert_eq!(parse_key("K").unwrap(), KeyCode::Char('k'));
+    assert_eq!(parse_key("escape").unwrap(), KeyCode::Esc);
+    assert!(parse_key("hyper").is_err());
+    assert!(parse_key("f13").is_err());
+}
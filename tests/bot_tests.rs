@@ -0,0 +1,109 @@
+// tests/bot_tests.rs
+use snake_game::bot::{bot_by_name, AStarBot, Bot, GameView, GreedyBot, BOT_NAMES};
+use snake_game::config::Config;
+use snake_game::core::GameState;
+use snake_game::entities::{Direction, Point};
+use snake_game::gameplay::{GameMode, GameState as GameStateEnum, WallMode};
+
+fn seeded_game(seed: u64) -> GameState {
+    let mut config = Config::new();
+    config.seed = Some(seed);
+    GameState::with_config(config)
+}
+
+// Lets the bot steer for up to `ticks` ticks, moving on between levels and stopping if the game ends
+fn autoplay(game: &mut GameState, bot: &mut dyn Bot, ticks: u32) {
+    for _ in 0..ticks {
+        match game.game_state() {
+            GameStateEnum::Playing => {}
+            GameStateEnum::LevelTransition => game.start_next_level(),
+            _ => break,
+        }
+        let direction = bot.next_direction(&GameView::new(game));
+        game.change_direction(direction);
+        game.update().unwrap();
+    }
+}
+
+#[test]
+fn test_greedy_bot_eats_food() {
+    for seed in 0..5 {
+        let mut game = seeded_game(seed);
+        autoplay(&mut game, &mut GreedyBot::new(), 200);
+        assert!(game.score() > 0, "seed {} scored nothing", seed);
+    }
+}
+
+#[test]
+fn test_greedy_bot_never_reverses_into_its_neck() {
+    let mut game = seeded_game(11);
+    let mut bot = GreedyBot::new();
+    for _ in 0..50 {
+        let direction = bot.next_direction(&GameView::new(&game));
+        assert_ne!(direction, game.snake().direction().opposite());
+        game.change_direction(direction);
+        game.update().unwrap();
+    }
+}
+
+#[test]
+fn test_view_sees_walls_bodies_and_wrapping() {
+    let game = seeded_game(1);
+    let view = GameView::new(&game);
+    assert!(view.is_blocked(&Point::new(0, 0)));
+    assert!(view.is_blocked(&view.body()[0]));
+    assert_eq!(view.head(), *game.snake().head().unwrap());
+
+    let mut config = Config::new();
+    config.wall_mode = WallMode::Wrap;
+    let game = GameState::with_config(config);
+    let view = GameView::new(&game);
+    let (width, _) = view.dimensions();
+    assert_eq!(view.step(&Point::new(width - 3, 5), Direction::Right), Point::new(2, 5));
+}
+
+#[test]
+fn test_bots_are_found_by_name() {
+    for name in BOT_NAMES {
+        assert_eq!(bot_by_name(name).unwrap().name(), name);
+    }
+    assert!(bot_by_name("Greedy").is_some());
+    assert!(bot_by_name("nobody").is_none());
+}
+
+#[test]
+fn test_astar_path_reaches_food_around_obstacles() {
+    let game = seeded_game(4);
+    let view = GameView::new(&game);
+    let mut bot = AStarBot::new();
+    let direction = bot.next_direction(&view);
+
+    let path = bot.path();
+    assert_eq!(path.last(), Some(&view.food()));
+    assert_eq!(path[0], view.step(&view.head(), direction));
+    assert!(path.iter().all(|point| !view.is_wall(point) && !view.body().contains(point)));
+    assert!(path.windows(2).all(|pair| view.distance(&pair[0], &pair[1]) == 1));
+}
+
+#[test]
+fn test_astar_bot_outlasts_the_greedy_bot() {
+    // Endless levels keep going until the snake crashes
+    let endless = |seed| {
+        let mut config = Config::new();
+        config.mode = GameMode::Endless;
+        config.seed = Some(seed);
+        GameState::with_config(config)
+    };
+
+    let (mut astar_total, mut greedy_total) = (0, 0);
+    for seed in 0..2 {
+        let mut game = endless(seed);
+        autoplay(&mut game, &mut AStarBot::new(), 1500);
+        astar_total += game.score();
+
+        let mut game = endless(seed);
+        autoplay(&mut game, &mut GreedyBot::new(), 1500);
+        greedy_total += game.score();
+    }
+    assert!(astar_total >= greedy_total, "astar {} vs greedy {}", astar_total, greedy_total);
+}
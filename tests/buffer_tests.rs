@@ -0,0 +1,50 @@
+// tests/buffer_tests.rs
+use snake_game::ui::{Cell, FrameBuffer};
+use crossterm::style::Color;
+
+fn present(buffer: &mut FrameBuffer) -> String {
+    let mut out = Vec::new();
+    buffer.present(&mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_unchanged_frame_emits_nothing() {
+    let mut buffer = FrameBuffer::new(10, 5);
+    buffer.set(3, 2, Cell::new('#', Color::Green, Color::Reset));
+
+    let first = present(&mut buffer);
+    assert!(first.contains('#'), "First frame should be drawn in full");
+
+    let second = present(&mut buffer);
+    assert!(second.is_empty(), "Identical frame should not write anything, got {:?}", second);
+}
+
+#[test]
+fn test_only_changed_cells_are_emitted() {
+    let mut buffer = FrameBuffer::new(10, 5);
+    present(&mut buffer);
+
+    buffer.set(4, 1, Cell::new('@', Color::Red, Color::Reset));
+    let output = present(&mut buffer);
+    assert!(output.contains('@'));
+    assert_eq!(output.matches(' ').count(), 0, "Untouched cells should not be redrawn");
+}
+
+#[test]
+fn test_invalidate_forces_full_redraw() {
+    let mut buffer = FrameBuffer::new(4, 2);
+    present(&mut buffer);
+
+    buffer.invalidate();
+    let output = present(&mut buffer);
+    assert_eq!(output.matches(' ').count(), 8, "Every cell should be redrawn after invalidate");
+}
+
+#[test]
+fn test_out_of_bounds_writes_are_ignored() {
+    let mut buffer = FrameBuffer::new(4, 2);
+    buffer.set(10, 10, Cell::new('x', Color::Reset, Color::Reset));
+    assert!(buffer.get(10, 10).is_none());
+    assert_eq!(buffer.get(0, 0), Some(&Cell::default()));
+}
@@ -1,6 +1,7 @@
 // tests/collision_tests.rs
 
-use snake_game::core::CollisionManager;
+use snake_game::core::{CollisionManager, GameState};
+use snake_game::gameplay::{DeathCause, GameEndReason, GameState as GameStateEnum};
 use snake_game::entities::Point;  // Removed unused Obstacle
 use std::collections::VecDeque;
 
@@ -39,4 +40,16 @@ fn test_self_collision() {
     straight_snake.push_back(Point::new(7, 5));
     
     assert!(!manager.is_self_collision(&straight_snake), "Should not detect collision in straight snake");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_death_cause_is_recorded() {
+    // Nothing stands on the start row, so heading right ends at the wall
+    let mut game = GameState::new();
+    assert_eq!(game.death_cause(), None);
+    while game.game_state() == GameStateEnum::Playing {
+        game.update().unwrap();
+    }
+    assert_eq!(game.game_state(), GameStateEnum::GameOver(GameEndReason::Collision));
+    assert_eq!(game.death_cause(), Some(DeathCause::Wall));
+}
@@ -0,0 +1,87 @@
+// tests/difficulty_tests.rs
+use snake_game::core::{GameState, HighScores};
+use snake_game::config::{Config, Difficulty};
+use snake_game::gameplay::{GameMode, GameState as GameStateEnum, WallMode};
+use snake_game::ui::render_text;
+
+#[test]
+fn test_preset_lookup() {
+    assert_eq!(Difficulty::from_name("hard"), Some(Difficulty::Hard));
+    assert_eq!(Difficulty::from_name("INSANE"), Some(Difficulty::Insane));
+    assert_eq!(Difficulty::from_name("nightmare"), None);
+}
+
+#[test]
+fn test_presets_ramp_up() {
+    let easy = Config::from_difficulty(Difficulty::Easy);
+    let normal = Config::from_difficulty(Difficulty::Normal);
+    let insane = Config::from_difficulty(Difficulty::Insane);
+
+    assert!(easy.tick_rate_ms > normal.tick_rate_ms && normal.tick_rate_ms > insane.tick_rate_ms);
+    assert!(easy.base_obstacles < insane.base_obstacles);
+    assert!(insane.score_multiplier > normal.score_multiplier);
+    assert_eq!(easy.wall_mode, WallMode::Wrap);
+    assert_eq!(normal.wall_mode, WallMode::Solid);
+}
+
+#[test]
+fn test_classic_obstacle_density_follows_preset() {
+    let blocks = |difficulty: Difficulty| {
+        let mut config = Config::from_difficulty(difficulty);
+        config.starting_level = 2;
+        let game = GameState::with_config(config);
+        game.obstacles().iter().map(|obstacle| obstacle.blocks.len()).sum::<usize>()
+    };
+
+    assert!(blocks(Difficulty::Easy) < blocks(Difficulty::Normal));
+    assert!(blocks(Difficulty::Normal) < blocks(Difficulty::Hard));
+    assert!(blocks(Difficulty::Hard) < blocks(Difficulty::Insane));
+}
+
+#[test]
+fn test_status_keeps_score_and_speed_on_every_preset() {
+    for difficulty in Difficulty::ALL {
+        let game = GameState::with_config(Config::from_difficulty(difficulty));
+        let text = render_text(&game);
+        let status = text.lines().last().unwrap();
+        assert!(status.contains("Score: 0/") && status.contains("Speed: 1"), "{:?}: {}", difficulty, status);
+    }
+}
+
+#[test]
+fn test_wrapping_walls() {
+    let mut game = GameState::with_config(Config::from_difficulty(Difficulty::Easy));
+    let (width, _) = game.dimensions();
+
+    // Moving right across the whole board should wrap instead of crashing
+    for _ in 0..width {
+        game.update().unwrap();
+    }
+    assert!(!matches!(game.game_state(), GameStateEnum::GameOver(_)), "Easy walls should wrap");
+}
+
+#[test]
+fn test_high_scores_per_preset() {
+    let mut scores = HighScores::new();
+    let hard = HighScores::key(Difficulty::Hard, GameMode::Classic);
+    let easy = HighScores::key(Difficulty::Easy, GameMode::Classic);
+
+    assert!(scores.record(&hard, 12), "First score should be a new best");
+    assert!(!scores.record(&hard, 7), "Lower score should not be a new best");
+    assert_eq!(scores.top(&hard), &[12, 7]);
+    assert_eq!(scores.best(&easy), None, "Presets should not share tables");
+    assert_ne!(hard, HighScores::key(Difficulty::Hard, GameMode::Endless));
+    assert_ne!(hard, HighScores::campaign_key(Difficulty::Hard, GameMode::Classic));
+}
+
+#[test]
+fn test_high_scores_round_trip() {
+    let path = std::env::temp_dir().join("snake_highscores_round_trip.json");
+    let mut scores = HighScores::new();
+    scores.record("normal", 9);
+    scores.save(&path).unwrap();
+
+    let loaded = HighScores::load(&path).unwrap();
+    assert_eq!(loaded.best("normal"), Some(9));
+    std::fs::remove_file(&path).unwrap();
+}
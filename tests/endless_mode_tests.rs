@@ -0,0 +1,77 @@
+// tests/endless_mode_tests.rs
+use snake_game::core::GameState;
+use snake_game::config::Config;
+use snake_game::entities::Point;
+use snake_game::gameplay::{GameMode, LevelState, get_endless_pattern};
+
+#[test]
+fn test_endless_levels_keep_advancing() {
+    let mut state = LevelState::endless(1, 5);
+
+    for _ in 0..10 {
+        state.advance();
+    }
+    assert_eq!(state.current_level, 11, "Endless levels should not stop at a maximum");
+    assert!(state.score_needed_for_next().is_some(), "Endless levels always have a next target");
+}
+
+#[test]
+fn test_endless_score_targets_scale() {
+    let mut state = LevelState::endless(1, 5);
+    assert!(state.should_advance(5), "Level 1 should need 5 points");
+
+    state.advance();
+    assert!(!state.should_advance(10), "Level 2 should need more than the classic 10 points");
+    assert!(state.should_advance(15), "Level 2 should need 15 points");
+}
+
+#[test]
+fn test_endless_obstacles_grow_within_bounds() {
+    let config = Config::new();
+    let mut rng = rand::thread_rng();
+
+    let early = get_endless_pattern(1, &config, &mut rng);
+    let late = get_endless_pattern(6, &config, &mut rng);
+    assert!(late.positions.len() > early.positions.len(), "Later levels should have more obstacles");
+
+    for ((x, y), (w, h)) in late.positions.iter().zip(late.sizes.iter()) {
+        assert!(*x >= 4 && *x + w <= config.width - 4, "Obstacle outside horizontal margin");
+        assert!(*y >= 4 && *y + h <= config.height - 4, "Obstacle outside vertical margin");
+    }
+}
+
+#[test]
+fn test_endless_game_starts_playing() {
+    let mut config = Config::new();
+    config.mode = GameMode::Endless;
+
+    let game = GameState::with_config(config);
+    assert_eq!(game.mode(), GameMode::Endless);
+    assert_eq!(game.current_level(), 1);
+    assert!(game.score_needed_for_next().is_some());
+}
+
+#[test]
+fn test_endless_pattern_without_sizes_is_empty() {
+    let mut config = Config::new();
+    config.obstacle_sizes.clear();
+    let pattern = get_endless_pattern(5, &config, &mut rand::thread_rng());
+    assert!(pattern.positions.is_empty() && pattern.sizes.is_empty());
+}
+
+#[test]
+fn test_endless_start_is_clear_ahead_of_the_snake() {
+    for seed in 0..20 {
+        let mut config = Config::new();
+        config.mode = GameMode::Endless;
+        config.starting_level = 8;
+        config.seed = Some(seed);
+        let game = GameState::with_config(config);
+
+        let head = *game.snake().head().unwrap();
+        for x in head.x + 1..=head.x + 5 {
+            let ahead = Point::new(x, head.y);
+            assert!(!game.obstacles().iter().any(|o| o.collides_with(&ahead)), "seed {} blocks {:?}", seed, ahead);
+        }
+    }
+}
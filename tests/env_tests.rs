@@ -0,0 +1,133 @@
+// tests/env_tests.rs
+use snake_game::bot::{AStarBot, Bot, GameView};
+use snake_game::entities::Direction;
+use snake_game::env::{
+    Encoding, EnvOptions, Rewards, SnakeEnv, VecEnv, FEATURE_COUNT, GRID_CHANNELS, RAY_COUNT, RAY_VALUES,
+};
+use snake_game::gameplay::{DeathCause, GameEndReason, GameEvent};
+
+fn env_with(encoding: Encoding) -> SnakeEnv {
+    SnakeEnv::new(EnvOptions { encoding, ..EnvOptions::default() })
+}
+
+// Lets the A* bot pick the action, so episodes get somewhere
+fn bot_action(env: &SnakeEnv, bot: &mut AStarBot) -> Direction {
+    bot.next_direction(&GameView::new(env.game()))
+}
+
+#[test]
+fn test_same_seed_same_episode() {
+    let mut a = env_with(Encoding::Rays);
+    let mut b = env_with(Encoding::Rays);
+    assert_eq!(a.reset(7), b.reset(7));
+
+    for action in [Direction::Down, Direction::Right, Direction::Right, Direction::Up] {
+        let (obs_a, reward_a, done_a, info_a) = a.step(action).unwrap();
+        let (obs_b, reward_b, done_b, info_b) = b.step(action).unwrap();
+        assert_eq!((obs_a, reward_a, done_a), (obs_b, reward_b, done_b));
+        assert_eq!(info_a, info_b);
+    }
+}
+
+#[test]
+fn test_encodings_have_their_shapes() {
+    let mut env = env_with(Encoding::Grid);
+    let obs = env.reset(1);
+    let (width, height) = env.game().dimensions();
+    let (width, height) = (usize::from(width) - 4, usize::from(height) - 4);
+    assert_eq!(obs.shape, vec![GRID_CHANNELS, height, width]);
+    assert_eq!(obs.data.len(), GRID_CHANNELS * width * height);
+
+    // One head, one food and every obstacle block on their planes
+    let plane = |channel: usize| obs.data[channel * width * height..(channel + 1) * width * height].iter().sum::<f32>();
+    let blocks: usize = env.game().obstacles().iter().map(|obstacle| obstacle.blocks.len()).sum();
+    assert_eq!(plane(0), 1.0);
+    assert_eq!(plane(1), env.game().snake().body().len() as f32);
+    assert_eq!(plane(2), 1.0);
+    assert_eq!(plane(3), blocks as f32);
+
+    let mut env = env_with(Encoding::Features);
+    assert_eq!(env.reset(1).data.len(), FEATURE_COUNT);
+    let mut env = env_with(Encoding::Rays);
+    let obs = env.reset(1);
+    assert_eq!(obs.shape, vec![RAY_COUNT, RAY_VALUES]);
+    assert!(obs.data.chunks(RAY_VALUES).all(|ray| ray[0] > 0.0), "every ray ends at a wall");
+}
+
+#[test]
+fn test_rewards_follow_the_game() {
+    let rewards = Rewards { step: 0.0, ..Rewards::default() };
+    let mut env = SnakeEnv::new(EnvOptions { rewards, ..EnvOptions::default() });
+    env.reset(3);
+    let mut bot = AStarBot::new();
+
+    let (reward, info) = loop {
+        let action = bot_action(&env, &mut bot);
+        let (_, reward, done, info) = env.step(action).unwrap();
+        assert!(!done);
+        if info.score > 0 {
+            break (reward, info);
+        }
+        assert_eq!(reward, 0.0);
+    };
+    assert_eq!(reward, rewards.food);
+    assert!(matches!(info.events.as_slice(), [.., GameEvent::FoodEaten { .. }]));
+
+    // Steering up and nothing else crashes sooner or later
+    let (reward, info) = loop {
+        let (_, reward, done, info) = env.step(Direction::Up).unwrap();
+        if done {
+            break (reward, info);
+        }
+    };
+    assert_eq!(reward, rewards.death);
+    assert_eq!(info.end, Some(GameEndReason::Collision));
+    assert!(matches!(info.death, Some(DeathCause::Wall | DeathCause::Obstacle | DeathCause::Itself)));
+    assert!(env.step(Direction::Up).is_err());
+}
+
+#[test]
+fn test_levels_carry_on_within_an_episode() {
+    let mut env = env_with(Encoding::Features);
+    env.reset(3);
+    let mut bot = AStarBot::new();
+
+    let mut level_rewards = 0;
+    for _ in 0..2000 {
+        let action = bot_action(&env, &mut bot);
+        let (_, reward, done, info) = env.step(action).unwrap();
+        if info.events.iter().any(|event| matches!(event, GameEvent::LevelComplete { .. })) {
+            assert!(reward >= env.options().rewards.level_complete);
+            level_rewards += 1;
+        }
+        if done || info.level == 2 {
+            break;
+        }
+    }
+    assert_eq!(level_rewards, 1);
+    assert_eq!(env.game().current_level(), 2);
+    assert!(!env.is_done());
+}
+
+#[test]
+fn test_vector_env_resets_finished_episodes() {
+    let options = EnvOptions { max_steps: 5, ..EnvOptions::default() };
+    let mut envs = VecEnv::new(options, 3);
+    let observations = envs.reset(10);
+    assert_eq!(observations.len(), 3);
+    assert_ne!(observations[0], observations[1]);
+
+    assert!(envs.step(&[Direction::Right]).is_err());
+    for step in 1..=5 {
+        let steps = envs.step(&[Direction::Right, Direction::Down, Direction::Right]).unwrap();
+        for (observation, _, done, info) in steps {
+            if step < 5 {
+                assert!(!done && info.final_observation.is_none());
+                continue;
+            }
+            assert!(done && info.truncated);
+            assert_ne!(info.final_observation, Some(observation));
+        }
+    }
+    assert!(envs.envs().iter().all(|env| !env.is_done() && env.game().score() == 0));
+}
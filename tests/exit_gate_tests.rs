@@ -0,0 +1,141 @@
+// tests/exit_gate_tests.rs
+use snake_game::bot::{AStarBot, Bot, GameView, HamiltonianBot};
+use snake_game::core::GameState;
+use snake_game::entities::Direction;
+use snake_game::config::Config;
+use snake_game::gameplay::{GameEvent, GameState as GameStateEnum, Objective};
+
+fn gate_game() -> GameState {
+    let mut config = Config::new();
+    config.exit_gate = true;
+    config.level_objectives = vec![Objective::Survive { seconds: 1 }, Objective::Score];
+    GameState::with_config(config)
+}
+
+// Eating food speeds the ticks up, so count time rather than ticks
+fn survive_one_second(game: &mut GameState) {
+    while game.level_elapsed_ms() < 1000 && game.game_state() == GameStateEnum::Playing {
+        game.update().unwrap();
+    }
+}
+
+#[test]
+fn test_gate_opens_when_target_met() {
+    let mut game = gate_game();
+    assert!(game.exit_gate().is_none(), "Gate should start closed");
+
+    survive_one_second(&mut game);
+    assert!(game.exit_gate().is_some(), "Gate should open once the objective is met");
+    assert_eq!(game.game_state(), GameStateEnum::Playing, "Level should not end before the gate");
+    assert!(game.drain_events().iter().any(|e| matches!(e, GameEvent::GateOpened { .. })));
+}
+
+#[test]
+fn test_gate_is_reachable_position() {
+    let mut game = gate_game();
+    survive_one_second(&mut game);
+
+    let gate = *game.exit_gate().unwrap();
+    let (width, height) = game.dimensions();
+    assert!(gate.x >= 2 && gate.x < width - 2 && gate.y >= 2 && gate.y < height - 2);
+    assert!(!game.obstacles().iter().any(|o| o.collides_with(&gate)), "Gate should not be inside an obstacle");
+    assert!(!game.snake().body().contains(&gate), "Gate should not be under the snake");
+}
+
+// No obstacles, so the snake can be steered straight to the gate
+fn open_gate_game(objectives: Vec<Objective>, width: u16, height: u16, seed: u64) -> GameState {
+    let mut config = Config::new();
+    config.width = width;
+    config.height = height;
+    config.base_obstacles = 0;
+    config.obstacles_per_level = 0;
+    config.exit_gate = true;
+    config.level_objectives = objectives;
+    config.seed = Some(seed);
+    GameState::with_config(config)
+}
+
+#[test]
+fn test_only_the_gate_completes_the_level() {
+    let mut game = open_gate_game(vec![Objective::Survive { seconds: 1 }, Objective::Score], 50, 25, 9);
+    while game.exit_gate().is_none() {
+        game.update().unwrap();
+    }
+    game.drain_events();
+    let gate = *game.exit_gate().unwrap();
+
+    for _ in 0..200 {
+        let head = *game.snake().head().unwrap();
+        let heading = game.snake().direction();
+        let toward = [
+            (gate.x > head.x).then_some(Direction::Right),
+            (gate.x < head.x).then_some(Direction::Left),
+            (gate.y > head.y).then_some(Direction::Down),
+            (gate.y < head.y).then_some(Direction::Up),
+        ];
+        let direction = toward.into_iter().flatten()
+            .find(|direction| *direction != heading.opposite())
+            .unwrap_or(if head.y > 3 { Direction::Up } else { Direction::Down });
+        game.change_direction(direction);
+        game.update().unwrap();
+
+        let completed = game.drain_events().iter().any(|e| matches!(e, GameEvent::LevelComplete { .. }));
+        if game.snake().head() == Some(&gate) {
+            assert!(completed, "Reaching the gate should complete the level");
+            assert_eq!(game.game_state(), GameStateEnum::LevelTransition);
+            return;
+        }
+        assert!(!completed, "Only the gate square completes the level");
+        assert_eq!(game.game_state(), GameStateEnum::Playing);
+    }
+    panic!("Snake never reached the gate at {:?}", gate);
+}
+
+#[test]
+fn test_full_board_completes_the_level_without_a_gate() {
+    // 16x8 playing squares: at length 127 the food takes the last free one
+    let mut game = open_gate_game(vec![Objective::ReachLength(127), Objective::Score], 20, 12, 9);
+    let mut bot = HamiltonianBot::new();
+
+    for _ in 0..100_000 {
+        if game.game_state() != GameStateEnum::Playing {
+            break;
+        }
+        let direction = bot.next_direction(&GameView::new(&game));
+        game.change_direction(direction);
+        game.update().unwrap();
+    }
+
+    assert_eq!(game.game_state(), GameStateEnum::LevelTransition);
+    assert!(game.exit_gate().is_none());
+    assert!(game.drain_events().iter().any(|e| matches!(e, GameEvent::LevelComplete { .. })));
+}
+
+#[test]
+fn test_food_never_lands_on_the_gate() {
+    // 4x4 playing squares, so a meal with the gate open often has few squares to pick from
+    let mut meals = 0;
+    for seed in 0..30 {
+        let mut game = open_gate_game(vec![Objective::Survive { seconds: 1 }, Objective::Score], 8, 8, seed);
+        let mut bot = AStarBot::new();
+
+        for _ in 0..500 {
+            if game.game_state() != GameStateEnum::Playing {
+                break;
+            }
+            let direction = bot.next_direction(&GameView::new(&game));
+            game.change_direction(direction);
+            let food = *game.food().position();
+            game.update().unwrap();
+
+            let Some(gate) = game.exit_gate() else {
+                continue;
+            };
+            if *game.food().position() != food {
+                meals += 1;
+            }
+            assert_ne!(game.food().position(), gate, "Seed {}: food under the gate", seed);
+        }
+    }
+    assert!(meals >= 20, "The bots should have eaten with the gate open, ate {}", meals);
+}
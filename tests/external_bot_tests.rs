@@ -0,0 +1,90 @@
+// tests/external_bot_tests.rs
+#![cfg(unix)]
+use std::time::Duration;
+use snake_game::bot::{Bot, BotState, ExternalBot, GameView};
+use snake_game::config::Config;
+use snake_game::core::GameState;
+use snake_game::entities::Direction;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+fn ask(command: &str, timeout: Duration) -> (Direction, Option<String>) {
+    let game = GameState::new();
+    let mut bot = ExternalBot::spawn(command, timeout).unwrap();
+    let direction = bot.next_direction(&GameView::new(&game));
+    (direction, bot.warning())
+}
+
+#[test]
+fn test_state_line_uses_playable_coordinates() {
+    let game = GameState::new();
+    let state = BotState::new(&GameView::new(&game), 1);
+    let json: serde_json::Value = serde_json::to_value(&state).unwrap();
+    let (width, height) = game.dimensions();
+
+    assert_eq!(json["width"], width - 4);
+    assert_eq!(json["height"], height - 4);
+    assert_eq!(json["direction"], "right");
+    let head = game.snake().head().unwrap();
+    assert_eq!(json["snake"][0], serde_json::json!([head.x - 2, head.y - 2]));
+    assert_eq!(json["snake"].as_array().unwrap().len(), game.snake().body().len());
+    assert!(json["exit_gate"].is_null());
+}
+
+#[test]
+fn test_replies_steer_the_snake() {
+    // Each state line is read, and the direction echoed back in any case
+    let (direction, warning) = ask("while read state; do echo ' Down'; done", TIMEOUT);
+    assert_eq!(direction, Direction::Down);
+    assert_eq!(warning, None);
+}
+
+#[test]
+fn test_bad_or_late_replies_keep_going_straight() {
+    let (direction, warning) = ask("while read state; do echo sideways; done", TIMEOUT);
+    assert_eq!(direction, Direction::Right);
+    assert!(warning.unwrap().contains("'sideways'"));
+
+    let (direction, warning) = ask("while read state; do sleep 2; echo up; done", Duration::from_millis(50));
+    assert_eq!(direction, Direction::Right);
+    assert!(warning.unwrap().contains("longer than 50ms"));
+}
+
+#[test]
+fn test_late_reply_is_not_used_for_the_next_tick() {
+    // The first answer misses its tick; the second state is answered straight away
+    let game = GameState::new();
+    let command = "read state; sleep 0.2; echo up; while read state; do echo down; done";
+    let mut bot = ExternalBot::spawn(command, Duration::from_millis(150)).unwrap();
+
+    assert_eq!(bot.next_direction(&GameView::new(&game)), Direction::Right);
+    assert!(bot.warning().unwrap().contains("longer than 150ms"));
+    assert_eq!(bot.next_direction(&GameView::new(&game)), Direction::Down, "The late 'up' belongs to the first tick");
+    assert_eq!(bot.warning(), None);
+}
+
+#[test]
+fn test_wait_is_capped_at_the_tick() {
+    let mut config = Config::new();
+    config.tick_rate_ms = 40;
+    config.min_tick_rate_ms = 40;
+    let game = GameState::with_config(config);
+    let mut bot = ExternalBot::spawn("while read state; do sleep 2; echo up; done", Duration::from_secs(5)).unwrap();
+
+    assert_eq!(bot.next_direction(&GameView::new(&game)), Direction::Right);
+    assert!(bot.warning().unwrap().contains("longer than 40ms"), "The bot may not hold up the next tick");
+}
+
+#[test]
+fn test_bot_that_exits_is_reported() {
+    let game = GameState::new();
+    let mut bot = ExternalBot::spawn("read state; echo up; exit 3", TIMEOUT).unwrap();
+    assert_eq!(bot.next_direction(&GameView::new(&game)), Direction::Up);
+
+    // Give the program a moment to finish
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(bot.next_direction(&GameView::new(&game)), Direction::Right);
+    let warning = bot.warning().unwrap();
+    assert!(warning.contains("exited") || warning.contains("closed"), "{}", warning);
+    assert_eq!(bot.next_direction(&GameView::new(&game)), Direction::Right);
+}
@@ -0,0 +1,59 @@
+// tests/hint_tests.rs
+use crossterm::event::KeyCode;
+use snake_game::bot::{AStarBot, Bot, GameView};
+use snake_game::config::Config;
+use snake_game::core::GameState;
+use snake_game::ui::{Action, Context, Hint, KeyBindings, MemoryBackend, Renderer, Theme};
+
+fn seeded_game() -> GameState {
+    let mut config = Config::new();
+    config.seed = Some(3);
+    GameState::with_config(config)
+}
+
+fn renderer_for(game: &GameState) -> Renderer<MemoryBackend> {
+    let (width, height) = game.dimensions();
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(width, height + 1), width, height);
+    renderer.set_theme(Theme::ascii());
+    renderer
+}
+
+#[test]
+fn test_hint_path_is_dotted_up_to_the_food() {
+    let game = seeded_game();
+    let mut bot = AStarBot::new();
+    bot.next_direction(&GameView::new(&game));
+    assert!(bot.found_safe_path());
+    let path = bot.path().to_vec();
+
+    let mut renderer = renderer_for(&game);
+    renderer.set_hint(Hint::Path(path.clone()));
+    renderer.render(&game).unwrap();
+
+    let screen = renderer.backend();
+    let (food, dot) = path.split_last().unwrap();
+    assert!(dot.iter().all(|p| screen.cell(p.x, p.y).unwrap().ch == Theme::ascii().hint.ch));
+    assert_eq!(screen.cell(food.x, food.y).unwrap().ch, Theme::ascii().food.ch);
+}
+
+#[test]
+fn test_hint_off_draws_nothing_and_no_path_warns() {
+    let game = seeded_game();
+    let mut renderer = renderer_for(&game);
+    renderer.render(&game).unwrap();
+    let (_, height) = game.dimensions();
+    // The status bar has colons of its own, so only look at the board
+    let board = &renderer.backend().lines()[..usize::from(height)];
+    assert!(board.iter().all(|line| !line.contains(Theme::ascii().hint.ch)));
+
+    renderer.set_hint(Hint::NoSafePath);
+    renderer.render(&game).unwrap();
+    assert!(renderer.backend().line(height).contains("NO SAFE PATH"));
+}
+
+#[test]
+fn test_hint_key_toggles_in_game() {
+    let bindings = KeyBindings::default();
+    assert_eq!(bindings.action(KeyCode::Char('?'), Context::Game), Some(Action::Hint));
+    assert_eq!(bindings.action(KeyCode::Char('?'), Context::Menu), None);
+}
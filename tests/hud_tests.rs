@@ -0,0 +1,49 @@
+// tests/hud_tests.rs
+use snake_game::core::GameState;
+use snake_game::config::{Config, Difficulty};
+use snake_game::ui::{progress_bar, side_panel_lines, side_panel_width, MemoryBackend, Renderer, SIDE_PANEL_MAX_WIDTH};
+
+#[test]
+fn test_side_panel_adapts_to_width() {
+    assert_eq!(side_panel_width(50, 50), None, "No room beside a full-width board");
+    assert_eq!(side_panel_width(60, 50), None, "Too narrow to be useful");
+    assert_eq!(side_panel_width(68, 50), Some(16));
+    assert_eq!(side_panel_width(200, 50), Some(SIDE_PANEL_MAX_WIDTH));
+}
+
+#[test]
+fn test_progress_bar() {
+    assert_eq!(progress_bar(0, 10, 12), "[----------]");
+    assert_eq!(progress_bar(5, 10, 12), "[=====-----]");
+    assert_eq!(progress_bar(15, 10, 12), "[==========]");
+}
+
+#[test]
+fn test_side_panel_contents() {
+    let mut config = Config::from_difficulty(Difficulty::Hard);
+    config.seed = Some(1);
+    let game = GameState::with_config(config);
+
+    let lines = side_panel_lines(&game, Some(42), 20);
+    assert!(lines.iter().all(|line| line.chars().count() <= 20));
+    assert!(lines.contains(&"Best              42".to_string()), "{:?}", lines);
+    assert!(lines.contains(&"Length             3".to_string()));
+    assert!(lines.contains(&"Tick          150 ms".to_string()));
+    assert!(lines.iter().any(|line| line.trim() == "score x2"));
+}
+
+#[test]
+fn test_renderer_draws_side_panel_when_enabled() {
+    let mut config = Config::new();
+    config.width = 30;
+    config.height = 15;
+    let game = GameState::with_config(config);
+
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(60, 16), 30, 15);
+    renderer.set_side_panel(true);
+    renderer.render(&game).unwrap();
+
+    let screen = renderer.backend();
+    assert!(screen.lines().iter().any(|line| line.contains("Length")));
+    assert_eq!(screen.find('█'), Some((2, 0)), "Board and panel should be centered together");
+}
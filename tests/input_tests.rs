@@ -0,0 +1,39 @@
+// tests/input_tests.rs
+use snake_game::ui::InputEvent;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+fn key(code: KeyCode, modifiers: KeyModifiers, kind: KeyEventKind) -> InputEvent {
+    InputEvent::from_event(Event::Key(KeyEvent::new_with_kind(code, modifiers, kind))).unwrap()
+}
+
+#[test]
+fn test_key_events_keep_modifiers_and_kind() {
+    let event = key(KeyCode::Char('w'), KeyModifiers::SHIFT, KeyEventKind::Press);
+    assert_eq!(event, InputEvent::Key {
+        code: KeyCode::Char('w'),
+        modifiers: KeyModifiers::SHIFT,
+        kind: KeyEventKind::Press,
+    });
+    assert_eq!(event.pressed_key(), Some(KeyCode::Char('w')));
+
+    let repeat = key(KeyCode::Up, KeyModifiers::NONE, KeyEventKind::Repeat);
+    assert_eq!(repeat.pressed_key(), Some(KeyCode::Up));
+
+    let release = key(KeyCode::Up, KeyModifiers::NONE, KeyEventKind::Release);
+    assert_eq!(release.pressed_key(), None, "Releases should not count as presses");
+}
+
+#[test]
+fn test_ctrl_c_is_an_interrupt() {
+    assert!(key(KeyCode::Char('c'), KeyModifiers::CONTROL, KeyEventKind::Press).is_interrupt());
+    assert!(!key(KeyCode::Char('c'), KeyModifiers::NONE, KeyEventKind::Press).is_interrupt());
+}
+
+#[test]
+fn test_terminal_events() {
+    assert_eq!(InputEvent::from_event(Event::Resize(80, 24)), Some(InputEvent::Resize(80, 24)));
+    assert_eq!(InputEvent::from_event(Event::FocusLost), Some(InputEvent::FocusLost));
+    assert_eq!(InputEvent::from_event(Event::FocusGained), Some(InputEvent::FocusGained));
+    assert_eq!(InputEvent::from_event(Event::Paste("hello".to_string())), None);
+    assert_eq!(InputEvent::from_event(Event::Resize(80, 24)).unwrap().pressed_key(), None);
+}
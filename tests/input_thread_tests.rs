@@ -0,0 +1,55 @@
+// tests/input_thread_tests.rs
+use std::time::{Duration, Instant};
+use snake_game::ui::{InputEvent, InputThread};
+use snake_game::GameError;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+fn press(c: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+}
+
+#[test]
+fn test_events_arrive_in_order_with_timestamps() {
+    let mut pending = vec![press('w'), Event::FocusLost, press('a')].into_iter();
+    let started = Instant::now();
+    let input = InputThread::spawn_with(move |_| Ok(pending.next()));
+
+    // Give the thread time to read all three
+    std::thread::sleep(Duration::from_millis(50));
+    let events = input.events_until(Instant::now() + Duration::from_secs(1)).unwrap();
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1].event, InputEvent::FocusLost);
+    assert!(events.windows(2).all(|pair| pair[0].at <= pair[1].at));
+    assert!(events.iter().all(|timed| timed.at >= started));
+}
+
+#[test]
+fn test_no_input_times_out() {
+    let input = InputThread::spawn_with(|timeout| {
+        std::thread::sleep(timeout);
+        Ok(None)
+    });
+    let deadline = Instant::now() + Duration::from_millis(20);
+    assert!(input.events_until(deadline).unwrap().is_empty());
+    assert!(Instant::now() >= deadline, "Should wait until the deadline");
+}
+
+#[test]
+fn test_read_errors_are_reported() {
+    let mut calls = 0;
+    let input = InputThread::spawn_with(move |_| {
+        calls += 1;
+        match calls {
+            1 => Ok(Some(press('q'))),
+            _ => Err(GameError::InvalidInput("terminal went away".to_string())),
+        }
+    });
+
+    std::thread::sleep(Duration::from_millis(50));
+    let error = input.events_until(Instant::now() + Duration::from_secs(1)).unwrap_err();
+    assert!(error.to_string().contains("terminal went away"), "The error should surface, got {}", error);
+
+    // The thread has stopped, and says so instead of going quiet
+    assert!(input.events_until(Instant::now() + Duration::from_secs(1)).is_err());
+}
@@ -0,0 +1,93 @@
+// tests/multiplayer_tests.rs
+use crossterm::event::KeyCode;
+use snake_game::config::{Config, KeySettings};
+use snake_game::core::GameState;
+use snake_game::entities::{Direction, Point};
+use snake_game::gameplay::{GameEndReason, GameState as GameStateEnum};
+use snake_game::ui::{render_text, KeyBindings};
+
+// Smallest board, no obstacles: player 1 starts at (4, 2) heading right,
+// player 2 at (15, 9) heading left
+fn match_config(seed: u64) -> Config {
+    let mut config = Config::new();
+    config.width = 20;
+    config.height = 12;
+    config.base_obstacles = 0;
+    config.obstacles_per_level = 0;
+    config.players = 2;
+    config.seed = Some(seed);
+    config
+}
+
+// Runs `ticks` updates, queueing each (tick, player, direction) just before its tick
+fn play(game: &mut GameState, ticks: u32, turns: &[(u32, usize, Direction)]) {
+    for tick in 1..=ticks {
+        for &(_, player, direction) in turns.iter().filter(|(at, _, _)| *at == tick) {
+            game.queue_turn_for(player, direction);
+        }
+        game.update().unwrap();
+    }
+}
+
+#[test]
+fn test_players_start_apart_facing_inwards() {
+    let game = GameState::with_config(match_config(1));
+    let players = game.players();
+
+    assert_eq!(players.len(), 2);
+    assert_eq!(*players[0].snake().head().unwrap(), Point::new(4, 2));
+    assert_eq!(*players[1].snake().head().unwrap(), Point::new(15, 9));
+    assert_eq!(players[0].snake().direction(), Direction::Right);
+    assert_eq!(players[1].snake().direction(), Direction::Left);
+    assert!(players.iter().all(|player| player.is_alive() && player.score() == 0));
+}
+
+#[test]
+fn test_head_on_collision_takes_out_both_snakes() {
+    let mut game = GameState::with_config(match_config(1));
+
+    // Player 1 drops down column 6 as player 2 runs along row 9; both reach (6, 9) on tick 9
+    play(&mut game, 9, &[(3, 0, Direction::Down)]);
+
+    assert!(game.players().iter().all(|player| !player.is_alive()));
+    assert_eq!(game.game_state(), GameStateEnum::GameOver(GameEndReason::Draw));
+}
+
+#[test]
+fn test_running_into_another_snake_loses_the_match() {
+    let mut game = GameState::with_config(match_config(1));
+
+    // Player 1 runs down column 4 and then along row 8; player 2 turns up into its body
+    play(&mut game, 10, &[(1, 0, Direction::Down), (7, 0, Direction::Right), (10, 1, Direction::Up)]);
+
+    assert!(game.players()[0].is_alive());
+    assert!(!game.players()[1].is_alive());
+    assert_eq!(game.game_state(), GameStateEnum::GameOver(GameEndReason::Winner(0)));
+}
+
+#[test]
+fn test_each_player_steers_with_their_own_keys() {
+    let bindings = KeyBindings::default();
+    assert_eq!(bindings.player_move(KeyCode::Char('W')), Some((0, Direction::Up)));
+    assert_eq!(bindings.player_move(KeyCode::Left), Some((1, Direction::Left)));
+    assert_eq!(bindings.player_move(KeyCode::Char('l')), Some((2, Direction::Right)));
+    assert_eq!(bindings.player_move(KeyCode::Char('5')), Some((3, Direction::Down)));
+
+    let settings = KeySettings {
+        players: Some(vec![vec!["w".into(), "s".into(), "a".into(), "d".into()], vec!["w".into(), "x".into(), "y".into(), "z".into()]]),
+        ..Default::default()
+    };
+    assert!(KeyBindings::from_settings(&settings).is_err());
+}
+
+#[test]
+fn test_status_bar_shows_a_scoreboard() {
+    let mut config = match_config(3);
+    config.width = 50;
+    config.height = 25;
+    let game = GameState::with_config(config);
+
+    let frame = render_text(&game);
+    let status = frame.lines().last().unwrap();
+    assert!(status.contains("P1 0 | P2 0 | First to 15"), "status was {:?}", status);
+}
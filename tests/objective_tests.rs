@@ -0,0 +1,96 @@
+// tests/objective_tests.rs
+use snake_game::bot::{AStarBot, Bot, GameView};
+use snake_game::core::GameState;
+use snake_game::config::Config;
+use snake_game::gameplay::{GameEndReason, GameState as GameStateEnum, Objective};
+
+fn game_with_objectives(objectives: Vec<Objective>) -> GameState {
+    let mut config = Config::new();
+    config.level_objectives = objectives;
+    GameState::with_config(config)
+}
+
+#[test]
+fn test_objectives_define_levels() {
+    let game = game_with_objectives(vec![Objective::CollectInOrder(5), Objective::Score]);
+    assert_eq!(game.max_levels(), 2, "Each objective should be one level");
+    assert_eq!(game.objective(), Objective::CollectInOrder(5));
+    assert_eq!(game.pickups().len(), 5, "Pickups should be placed for the objective");
+    assert_eq!(game.objective_progress().target, 5);
+}
+
+#[test]
+fn test_survive_objective_completes_level() {
+    let mut game = game_with_objectives(vec![Objective::Survive { seconds: 1 }, Objective::Score]);
+
+    for _ in 0..10 {
+        game.update().unwrap();
+        if game.game_state() != GameStateEnum::Playing {
+            break;
+        }
+    }
+    assert_eq!(game.game_state(), GameStateEnum::LevelTransition, "Surviving should finish the level");
+    assert_eq!(game.current_level(), 2);
+}
+
+#[test]
+fn test_move_budget_runs_out() {
+    let mut game = game_with_objectives(vec![Objective::CollectAll { count: 3, moves: 2 }]);
+    assert_eq!(game.objective_progress().moves_left, Some(2));
+
+    game.update().unwrap();
+    game.update().unwrap();
+    assert_eq!(game.game_state(), GameStateEnum::GameOver(GameEndReason::OutOfMoves));
+}
+
+#[test]
+fn test_default_objective_is_score() {
+    let game = GameState::new();
+    assert_eq!(game.objective(), Objective::Score);
+    let progress = game.objective_progress();
+    assert_eq!((progress.current, progress.target), (0, 5));
+}
+
+#[test]
+fn test_pickups_stop_when_the_board_is_full() {
+    let mut config = Config::new();
+    config.width = 20;
+    config.height = 12;
+    config.level_objectives = vec![Objective::CollectInOrder(500)];
+    let game = GameState::with_config(config);
+
+    // 16x8 playing squares, less the snake, obstacles and food
+    let pickups = game.pickups();
+    assert!(!pickups.is_empty() && pickups.len() < 128, "Only as many pickups as fit: {}", pickups.len());
+    assert!(!pickups.contains(game.food().position()));
+    assert!(!pickups.iter().any(|p| game.snake().body().contains(p)));
+}
+
+#[test]
+fn test_food_never_lands_on_a_pickup() {
+    // Pickups cover about half the open board and stay put while the bot only chases food
+    for seed in 0..3 {
+        let mut config = Config::new();
+        config.width = 24;
+        config.height = 16;
+        config.base_obstacles = 0;
+        config.obstacles_per_level = 0;
+        config.level_objectives = vec![Objective::CollectInOrder(120)];
+        config.seed = Some(seed);
+        let mut game = GameState::with_config(config);
+        let mut bot = AStarBot::new();
+
+        let mut meals = 0;
+        while game.game_state() == GameStateEnum::Playing && meals < 40 {
+            let direction = bot.next_direction(&GameView::new(&game));
+            game.change_direction(direction);
+            let food = *game.food().position();
+            game.update().unwrap();
+            if *game.food().position() != food {
+                meals += 1;
+            }
+            assert!(!game.pickups().contains(game.food().position()), "Seed {}: food under a pickup", seed);
+        }
+        assert!(meals >= 10, "Seed {}: the bot should have eaten a few times, ate {}", seed, meals);
+    }
+}
@@ -180,7 +180,10 @@ fn test_speed_increase() {
 
 #[test]
 fn test_score_persistence() {
-    let mut game = GameState::new();
+    // Seeded so the next food can't turn up right in front of the snake
+    let mut config = Config::new();
+    config.seed = Some(1);
+    let mut game = GameState::with_config(config);
     let initial_score = game.score();
     let mut collected_food = false;
     
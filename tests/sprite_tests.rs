@@ -0,0 +1,45 @@
+// tests/sprite_tests.rs
+use std::collections::VecDeque;
+use snake_game::entities::{Direction, Point};
+use snake_game::ui::{direction_between, segment_roles, SegmentRole, SnakeGlyphs, Theme};
+
+fn body(points: &[(u16, u16)]) -> VecDeque<Point> {
+    points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn test_straight_snake_roles() {
+    // Tail at x=1, head at x=3 moving right
+    let roles = segment_roles(&body(&[(1, 5), (2, 5), (3, 5)]), Direction::Right);
+    assert_eq!(roles, vec![
+        SegmentRole::Tail(Direction::Right),
+        SegmentRole::Straight { horizontal: true },
+        SegmentRole::Head(Direction::Right),
+    ]);
+}
+
+#[test]
+fn test_corner_glyph() {
+    // Moving right, then turning down
+    let roles = segment_roles(&body(&[(1, 5), (2, 5), (2, 6)]), Direction::Down);
+    assert_eq!(roles[1], SegmentRole::Corner(Direction::Left, Direction::Down));
+    assert_eq!(roles[2], SegmentRole::Head(Direction::Down));
+    assert_eq!(SnakeGlyphs::box_drawing().glyph(roles[1]), '┓');
+}
+
+#[test]
+fn test_direction_between_wraps() {
+    assert_eq!(direction_between(&Point::new(0, 3), &Point::new(1, 3)), Some(Direction::Right));
+    assert_eq!(direction_between(&Point::new(49, 3), &Point::new(0, 3)), Some(Direction::Right));
+    assert_eq!(direction_between(&Point::new(4, 0), &Point::new(4, 24)), Some(Direction::Up));
+    assert_eq!(direction_between(&Point::new(4, 4), &Point::new(4, 4)), None);
+}
+
+#[test]
+fn test_ascii_snake_glyphs_are_ascii() {
+    let glyphs = Theme::ascii().snake_glyphs;
+    let all = glyphs.head.iter().chain(&glyphs.straight).chain(&glyphs.corners).chain(&glyphs.tail);
+    for ch in all {
+        assert!(ch.is_ascii(), "'{}' is not ASCII", ch);
+    }
+}
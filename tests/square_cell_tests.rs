@@ -0,0 +1,52 @@
+// tests/square_cell_tests.rs
+use snake_game::core::GameState;
+use snake_game::entities::Point;
+use snake_game::ui::{Cell, DisplayManager, FrameBuffer, MemoryBackend, Renderer};
+use crossterm::style::Color;
+
+fn square_display() -> DisplayManager {
+    let mut display = DisplayManager::new(10, 10);
+    display.set_square_cells(true);
+    display
+}
+
+#[test]
+fn test_square_coordinate_mapping() {
+    let mut display = square_display();
+    display.set_origin(3, 1);
+
+    assert_eq!(display.rows(), 5);
+    assert_eq!(display.screen_position(&Point::new(2, 0)), (5, 1));
+    assert_eq!(display.screen_position(&Point::new(2, 1)), (5, 1));
+    assert_eq!(display.screen_position(&Point::new(2, 7)), (5, 4));
+}
+
+#[test]
+fn test_half_blocks_combine_two_rows() {
+    let display = square_display();
+    let mut buffer = FrameBuffer::new(10, 6);
+
+    display.draw_cell(&mut buffer, &Point::new(1, 0), Cell::new('●', Color::Red, Color::Reset));
+    assert_eq!(buffer.get(1, 0), Some(&Cell::new('▀', Color::Red, Color::Reset)));
+
+    display.draw_cell(&mut buffer, &Point::new(1, 1), Cell::new('█', Color::Blue, Color::Blue));
+    assert_eq!(buffer.get(1, 0), Some(&Cell::new('▀', Color::Red, Color::Blue)));
+
+    display.draw_cell(&mut buffer, &Point::new(2, 3), Cell::new('█', Color::Green, Color::Reset));
+    assert_eq!(buffer.get(2, 1), Some(&Cell::new('▄', Color::Green, Color::Reset)));
+}
+
+#[test]
+fn test_square_board_fits_half_the_rows() {
+    let game = GameState::new();
+    let mut renderer = Renderer::with_backend(MemoryBackend::new(50, 14), 50, 25);
+    assert!(!renderer.fits_terminal());
+
+    renderer.set_square_cells(true);
+    assert!(renderer.fits_terminal());
+    renderer.render(&game).unwrap();
+
+    let screen = renderer.backend();
+    assert_eq!(screen.cell(0, 0), Some(Cell::new(' ', Color::Reset, Color::Blue)), "Both border rows are wall");
+    assert!(screen.line(13).contains("Level: 1/3"));
+}
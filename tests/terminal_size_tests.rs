@@ -0,0 +1,56 @@
+// tests/terminal_size_tests.rs
+use snake_game::core::GameState;
+use snake_game::config::{Config, MIN_BOARD_HEIGHT, MIN_BOARD_WIDTH};
+use snake_game::gameplay::GameState as GameStateEnum;
+use snake_game::ui::{DisplayManager, FrameBuffer};
+use crossterm::style::Color;
+
+#[test]
+fn test_board_fits_terminal() {
+    let mut config = Config::new();
+    config.fit_to_terminal(40, 20);
+    assert_eq!((config.width, config.height), (40, 19), "Board should leave a row for the status bar");
+
+    let mut roomy = Config::new();
+    roomy.fit_to_terminal(200, 60);
+    assert_eq!((roomy.width, roomy.height), (50, 25), "Board should not grow past the preset");
+
+    let mut tiny = Config::new();
+    tiny.fit_to_terminal(5, 5);
+    assert_eq!((tiny.width, tiny.height), (MIN_BOARD_WIDTH, MIN_BOARD_HEIGHT));
+}
+
+#[test]
+fn test_centered_box_wider_than_board() {
+    let display = DisplayManager::new(10, 5);
+    let mut buffer = FrameBuffer::new(10, 6);
+
+    // Used to underflow when the box was wider than the board
+    display.draw_centered_box(&mut buffer, "A message far wider than the board", Color::Blue, Color::White);
+    assert_eq!(buffer.get(0, 0).map(|c| c.bg), Some(Color::Blue));
+}
+
+#[test]
+fn test_paused_game_does_not_move() {
+    let mut game = GameState::new();
+    let head = *game.snake().head().unwrap();
+
+    game.pause();
+    assert_eq!(game.game_state(), GameStateEnum::Paused);
+    game.update().unwrap();
+    assert_eq!(*game.snake().head().unwrap(), head, "Snake should not move while paused");
+
+    game.resume();
+    game.update().unwrap();
+    assert_ne!(*game.snake().head().unwrap(), head);
+}
+
+#[test]
+fn test_snake_starts_inside_border_on_smallest_board() {
+    let mut config = Config::new();
+    config.fit_to_terminal(5, 5);
+    let mut game = GameState::with_config(config);
+
+    game.update().unwrap();
+    assert_eq!(game.game_state(), GameStateEnum::Playing, "Snake should not spawn in the wall");
+}
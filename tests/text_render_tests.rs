@@ -0,0 +1,46 @@
+// tests/text_render_tests.rs
+use snake_game::core::GameState;
+use snake_game::config::Config;
+use snake_game::ui::render_text;
+
+fn seeded_game(seed: u64) -> GameState {
+    game_of_width(30, seed)
+}
+
+fn game_of_width(width: u16, seed: u64) -> GameState {
+    let mut config = Config::new();
+    config.width = width;
+    config.height = 15;
+    config.seed = Some(seed);
+    GameState::with_config(config)
+}
+
+#[test]
+fn test_level_start_matches_golden_frame() {
+    // Wide enough that the status line isn't cut
+    let mut game = game_of_width(60, 7);
+    for _ in 0..3 {
+        game.update().unwrap();
+    }
+    let text = render_text(&game);
+    let status = text.lines().last().unwrap();
+    assert!(status.contains("Level: 1/") && status.contains("Score: 0/5") && status.contains("Speed: 1"), "{}", status);
+    assert_eq!(text, include_str!("golden/level1_after_3_ticks.txt"));
+}
+
+#[test]
+fn test_same_seed_gives_same_frame() {
+    assert_eq!(render_text(&seeded_game(11)), render_text(&seeded_game(11)));
+}
+
+#[test]
+fn test_text_frame_shape() {
+    let text = render_text(&seeded_game(3));
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 16, "Board rows plus the status line");
+    assert!(lines[..15].iter().all(|line| line.chars().count() == 30));
+    assert!(lines[15].contains("Score: 0/5"), "Score stays when the status is cut: {}", lines[15]);
+    assert_eq!(text.matches('*').count(), 1, "Exactly one food");
+    assert!(text.is_ascii());
+}
@@ -0,0 +1,62 @@
+// tests/theme_tests.rs
+use snake_game::config::{Settings, StyleOverride, ThemeSettings};
+use snake_game::ui::{parse_color, Theme, THEME_NAMES};
+use crossterm::style::Color;
+
+#[test]
+fn test_builtin_themes_exist() {
+    for name in THEME_NAMES {
+        let theme = Theme::by_name(name).unwrap_or_else(|| panic!("Missing theme {}", name));
+        assert_eq!(theme.name, name);
+    }
+    assert!(Theme::by_name("neon").is_none());
+}
+
+#[test]
+fn test_ascii_theme_is_pure_ascii() {
+    let theme = Theme::ascii();
+    for cell in [theme.snake, theme.wall, theme.obstacle, theme.food, theme.pickup, theme.gate] {
+        assert!(cell.ch.is_ascii(), "'{}' is not ASCII", cell.ch);
+    }
+}
+
+#[test]
+fn test_parse_color() {
+    assert_eq!(parse_color("dark_blue").unwrap(), Color::DarkBlue);
+    assert_eq!(parse_color("reset").unwrap(), Color::Reset);
+    assert_eq!(parse_color("#ff8000").unwrap(), Color::Rgb { r: 255, g: 128, b: 0 });
+    assert!(parse_color("#ff80").is_err());
+    assert!(parse_color("chartreuse").is_err());
+}
+
+#[test]
+fn test_settings_override_theme() {
+    let settings = ThemeSettings {
+        name: Some("ascii".to_string()),
+        food: Some(StyleOverride { glyph: Some('@'), fg: Some("cyan".to_string()), bg: None }),
+        ..ThemeSettings::default()
+    };
+
+    let theme = Theme::from_settings(&settings).unwrap();
+    assert_eq!(theme.food.ch, '@');
+    assert_eq!(theme.food.fg, Color::Cyan);
+    assert_eq!(theme.snake, Theme::ascii().snake, "Untouched elements keep the base theme");
+
+    let unknown = ThemeSettings { name: Some("neon".to_string()), ..ThemeSettings::default() };
+    assert!(Theme::from_settings(&unknown).is_err());
+}
+
+#[test]
+fn test_settings_file() {
+    let dir = std::env::temp_dir().join("snake_theme_settings_test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("snake.toml");
+    std::fs::write(&path, "[theme]\nname = \"high-contrast\"\n\n[theme.snake]\nglyph = \"S\"\n").unwrap();
+
+    let settings = Settings::load(dir.join("snake").to_str().unwrap()).unwrap();
+    let theme = Theme::from_settings(&settings.theme).unwrap();
+    assert_eq!(theme.name, "high-contrast");
+    assert_eq!(theme.snake.ch, 'S');
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
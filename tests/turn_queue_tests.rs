@@ -0,0 +1,44 @@
+// tests/turn_queue_tests.rs
+use snake_game::core::GameState;
+use snake_game::entities::Direction;
+use snake_game::gameplay::GameState as GameStateEnum;
+
+#[test]
+fn test_quick_double_turn_is_applied_over_two_ticks() {
+    let mut game = GameState::new();
+    let head = *game.snake().head().unwrap();
+
+    // Moving right: down then left within one tick used to reverse into the neck
+    game.queue_turn(Direction::Down);
+    game.queue_turn(Direction::Left);
+    assert_eq!(game.queued_turns().len(), 2);
+
+    game.update().unwrap();
+    assert_eq!(game.snake().direction(), Direction::Down);
+    game.update().unwrap();
+    assert_eq!(game.snake().direction(), Direction::Left);
+
+    assert_eq!(game.game_state(), GameStateEnum::Playing);
+    assert_eq!(*game.snake().head().unwrap(), snake_game::entities::Point::new(head.x - 1, head.y + 1));
+}
+
+#[test]
+fn test_redundant_and_reversing_turns_are_dropped() {
+    let mut game = GameState::new();
+    game.queue_turn(Direction::Right);
+    game.queue_turn(Direction::Left);
+    assert!(game.queued_turns().is_empty());
+
+    game.queue_turn(Direction::Down);
+    game.queue_turn(Direction::Up);
+    assert_eq!(game.queued_turns().len(), 1);
+}
+
+#[test]
+fn test_turn_queue_is_bounded() {
+    let mut game = GameState::new();
+    for direction in [Direction::Up, Direction::Left, Direction::Down, Direction::Right, Direction::Up] {
+        game.queue_turn(direction);
+    }
+    assert_eq!(game.queued_turns().len(), snake_game::MAX_QUEUED_TURNS);
+}